@@ -0,0 +1,159 @@
+//! Measure the memory and the lookup latency of a context with many keys and sparse translations.
+//!
+//! Run with `cargo bench --bench interning`.
+
+mod bench {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        collections::HashMap,
+        hint::black_box,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Instant,
+    };
+
+    use json_gettext::{JSONGetText, JSONGetTextValue};
+
+    const KEYS: usize = 40;
+    const TEXTS: usize = 2000;
+    const TRANSLATED_PERCENT: usize = 30;
+    const LOOKUPS: usize = 1_000_000;
+
+    struct CountingAllocator;
+
+    static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        #[inline]
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+
+            System.alloc(layout)
+        }
+
+        #[inline]
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    type Maps = Vec<(String, HashMap<String, JSONGetTextValue<'static>>)>;
+
+    fn text_id(i: usize) -> String {
+        format!("page.section_{}.text_{}", i / 100, i)
+    }
+
+    fn generate_maps() -> Maps {
+        (0..KEYS)
+            .map(|k| {
+                let map = (0..TEXTS)
+                    .filter(|i| k == 0 || (i * 7 + k) % 100 < TRANSLATED_PERCENT)
+                    .map(|i| (text_id(i), JSONGetTextValue::from_string(format!("{}-{}", k, i))))
+                    .collect();
+
+                (format!("key_{}", k), map)
+            })
+            .collect()
+    }
+
+    /// The original layout: every key owns a full map, where the untranslated texts are copies of the values of the default key, so lookups never fall back.
+    fn build_full_copies(
+        maps: Maps,
+    ) -> HashMap<String, HashMap<String, JSONGetTextValue<'static>>> {
+        let default_map = maps[0].1.clone();
+
+        maps.into_iter()
+            .map(|(key, map)| {
+                let mut full_map = default_map.clone();
+
+                full_map.extend(map);
+
+                (key, full_map)
+            })
+            .collect()
+    }
+
+    /// The layout used before interning: every key owns a map of its own texts with its own copies of their text ids, and lookups fall back to the map of the default key.
+    fn build_sparse_maps(
+        maps: Maps,
+    ) -> HashMap<String, HashMap<String, JSONGetTextValue<'static>>> {
+        maps.into_iter().collect()
+    }
+
+    fn build_interned(maps: Maps) -> JSONGetText<'static> {
+        let mut builder = JSONGetText::build("key_0");
+
+        for (key, map) in maps {
+            builder.add_map(key, map).unwrap();
+        }
+
+        builder.build().unwrap()
+    }
+
+    fn measure_memory<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATED.load(Ordering::Relaxed);
+
+        let output = f();
+
+        let after = ALLOCATED.load(Ordering::Relaxed);
+
+        (output, after.saturating_sub(before))
+    }
+
+    fn measure_latency(name: &str, mut f: impl FnMut(&str, &str) -> bool) {
+        let keys: Vec<String> = (0..KEYS).map(|k| format!("key_{}", k)).collect();
+        let texts: Vec<String> = (0..TEXTS).map(text_id).collect();
+
+        let start = Instant::now();
+
+        for n in 0..LOOKUPS {
+            assert!(f(&keys[n % KEYS], &texts[(n * 31) % TEXTS]));
+        }
+
+        let elapsed = start.elapsed();
+
+        println!("{:<12} lookup: {:>8.1} ns/op", name, elapsed.as_nanos() as f64 / LOOKUPS as f64);
+    }
+
+    pub fn run() {
+        println!("{} keys, {} texts, {}% translated", KEYS, TEXTS, TRANSLATED_PERCENT);
+
+        let (full_copies, full_copies_memory) =
+            measure_memory(|| build_full_copies(generate_maps()));
+        let (sparse_maps, sparse_maps_memory) =
+            measure_memory(|| build_sparse_maps(generate_maps()));
+        let (interned, interned_memory) = measure_memory(|| build_interned(generate_maps()));
+
+        println!("{:<12} memory: {:>8} KiB", "full copies", full_copies_memory / 1024);
+        println!("{:<12} memory: {:>8} KiB", "sparse maps", sparse_maps_memory / 1024);
+        println!("{:<12} memory: {:>8} KiB", "interned", interned_memory / 1024);
+
+        measure_latency("full copies", |key, text| {
+            black_box(full_copies.get(key).and_then(|map| map.get(text))).is_some()
+        });
+
+        let default_map = sparse_maps.get("key_0").unwrap();
+
+        measure_latency("sparse maps", |key, text| {
+            black_box(
+                sparse_maps
+                    .get(key)
+                    .and_then(|map| map.get(text))
+                    .or_else(|| default_map.get(text)),
+            )
+            .is_some()
+        });
+
+        measure_latency("interned", |key, text| {
+            black_box(interned.get_text_with_key(key, text)).is_some()
+        });
+    }
+}
+
+fn main() {
+    bench::run();
+}
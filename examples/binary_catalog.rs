@@ -0,0 +1,29 @@
+use std::{env, error::Error, fs::File, io::BufWriter};
+
+use json_gettext::{JSONGetText, JSONGetTextBinary};
+
+/// Usage: `cargo run --example binary_catalog -- <output> <default key> <key>=<json file>...`
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+
+    let output = args.next().ok_or("The output path is needed.")?;
+    let default_key = args.next().ok_or("The default key is needed.")?;
+
+    let mut builder = JSONGetText::build(default_key);
+
+    for arg in args {
+        let (key, path) = arg.split_once('=').ok_or("Sources must be `<key>=<json file>`.")?;
+
+        builder.add_json_file(key, path)?;
+    }
+
+    let ctx = builder.build()?;
+
+    ctx.write_binary(BufWriter::new(File::create(&output)?))?;
+
+    let catalog: JSONGetTextBinary = JSONGetText::from_binary_file(&output)?;
+
+    println!("{} locales are written to {}.", catalog.get().get_keys().len(), output);
+
+    Ok(())
+}
@@ -9,27 +9,35 @@ extern crate json_gettext;
 
 use std::error::Error;
 
-use json_gettext::{JSONGetTextManager, Key};
+use json_gettext::JSONGetTextManager;
 use rocket::State;
-use rocket_accept_language::{unic_langid::subtags::Language, AcceptLanguage};
+use rocket_accept_language::{
+    unic_langid::subtags::{Language, Region},
+    AcceptLanguage,
+};
 
 const LANGUAGE_EN: Language = language!("en");
 
 #[get("/")]
-fn index(ctx: &State<JSONGetTextManager>, accept_language: &AcceptLanguage) -> String {
+fn index(
+    ctx: &State<JSONGetTextManager<(Language, Option<Region>)>>,
+    accept_language: &AcceptLanguage,
+) -> String {
     let (language, region) =
         accept_language.get_first_language_region().unwrap_or((LANGUAGE_EN, None));
 
-    format!("Ron: {}", get_text!(ctx, Key(language, region), "hello").unwrap().as_str().unwrap())
+    let ctx = ctx.share();
+
+    format!("Ron: {}", get_text!(ctx, (language, region), "hello").unwrap().as_str().unwrap())
 }
 
 #[rocket::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     rocket::build()
         .attach(static_json_gettext_build_for_rocket!(
-            key!("en");
-            key!("en") => "langs/en_US.json",
-            key!("zh_TW") => "langs/zh_TW.json",
+            key!(language_region_pair: "en");
+            key!(language_region_pair: "en") => "langs/en_US.json",
+            key!(language_region_pair: "zh_TW") => "langs/zh_TW.json",
         ))
         .mount("/", routes![index])
         .launch()
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde_json::Value;
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Token,
+};
+
+use crate::common::read_json_object;
+
+struct CatalogInput {
+    name: Ident,
+    path: LitStr,
+}
+
+impl Parse for CatalogInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(Ident) {
+            let name = input.parse()?;
+
+            input.parse::<Token![,]>()?;
+
+            name
+        } else {
+            Ident::new("TextId", Span::call_site())
+        };
+
+        let path = input.parse()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(CatalogInput {
+            name,
+            path,
+        })
+    }
+}
+
+pub(crate) fn catalog(input: TokenStream) -> syn::Result<TokenStream> {
+    let CatalogInput {
+        name,
+        path,
+    } = syn::parse2(input)?;
+
+    let (full_path, object) = read_json_object(&path)?;
+
+    let full_path = full_path.to_string_lossy().into_owned();
+
+    let mut texts: Vec<(&String, &Value)> = object.iter().collect();
+
+    texts.sort_unstable_by_key(|(text, _)| *text);
+
+    let mut variant_texts: HashMap<String, &str> = HashMap::with_capacity(texts.len());
+
+    let mut variants = Vec::with_capacity(texts.len());
+    let mut strs = Vec::with_capacity(texts.len());
+    let mut docs = Vec::with_capacity(texts.len());
+
+    for (text, value) in texts {
+        let variant = to_variant_name(text);
+
+        if let Some(other) = variant_texts.insert(variant.clone(), text) {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "the text ids `{}` and `{}` both become the variant `{}`",
+                    other, text, variant
+                ),
+            ));
+        }
+
+        let doc = match value {
+            Value::String(s) => format!("`{}`: {}", text, s),
+            _ => format!("`{}`", text),
+        };
+
+        variants.push(to_variant_ident(text, &variant, path.span())?);
+        strs.push(text.as_str());
+        docs.push(doc);
+    }
+
+    let count = variants.len();
+
+    Ok(quote! {
+        /// Text ids generated from a JSON file.
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum #name {
+            #(
+                #[doc = #docs]
+                #variants,
+            )*
+        }
+
+        impl #name {
+            /// All text ids.
+            pub const ALL: [#name; #count] = [#(#name::#variants),*];
+
+            /// Get the text id as a string slice.
+            #[inline]
+            pub const fn as_str(self) -> &'static str {
+                // rebuild when the JSON file changes
+                const _: &[u8] = include_bytes!(#full_path);
+
+                match self {
+                    #(#name::#variants => #strs,)*
+                }
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::fmt::Display for #name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    })
+}
+
+/// Check that a variant name is a valid identifier, e.g. not `Self` (from the text id `self`) or containing a numeric character which cannot be in identifiers (e.g. `²`).
+fn to_variant_ident(text: &str, variant: &str, span: Span) -> syn::Result<Ident> {
+    match syn::parse_str::<Ident>(variant) {
+        Ok(_) if variant != "ALL" => Ok(Ident::new(variant, span)),
+        _ => Err(syn::Error::new(
+            span,
+            format!(
+                "the text id `{}` becomes `{}`, which cannot be the name of a variant",
+                text, variant
+            ),
+        )),
+    }
+}
+
+/// Convert a text id to an upper camel case identifier, e.g. `page.title` to `PageTitle`.
+fn to_variant_name(text: &str) -> String {
+    let mut name = String::with_capacity(text.len());
+
+    let mut upper_next = true;
+
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if upper_next {
+                name.extend(c.to_uppercase());
+            } else {
+                name.push(c);
+            }
+
+            upper_next = false;
+        } else {
+            upper_next = true;
+        }
+    }
+
+    if !name.starts_with(|c: char| c.is_alphabetic()) {
+        name.insert_str(0, "Text");
+    }
+
+    name
+}
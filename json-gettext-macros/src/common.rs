@@ -0,0 +1,23 @@
+use std::{env, fs, path::PathBuf};
+
+use proc_macro2::Span;
+use serde_json::{Map, Value};
+use syn::LitStr;
+
+/// Read a JSON object file whose path is relative to the `CARGO_MANIFEST_DIR`.
+pub(crate) fn read_json_object(path: &LitStr) -> syn::Result<(PathBuf, Map<String, Value>)> {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "`CARGO_MANIFEST_DIR` is not set"))?;
+
+    let full_path = PathBuf::from(manifest_dir).join(path.value());
+
+    let json = fs::read_to_string(&full_path).map_err(|err| {
+        syn::Error::new(path.span(), format!("cannot read `{}`: {}", full_path.display(), err))
+    })?;
+
+    let object = serde_json::from_str(&json).map_err(|err| {
+        syn::Error::new(path.span(), format!("`{}` is not a JSON object: {}", path.value(), err))
+    })?;
+
+    Ok((full_path, object))
+}
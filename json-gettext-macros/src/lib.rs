@@ -0,0 +1,54 @@
+/*!
+# JSON Get Text Macros
+
+Procedural macros for the [json-gettext](https://crates.io/crates/json-gettext) crate. Enable the `macros` feature of `json-gettext` and use them from there instead of depending on this crate directly.
+*/
+
+mod catalog;
+mod common;
+mod messages;
+mod verify;
+
+use proc_macro::TokenStream;
+
+/**
+Read a JSON file at compile time and generate an enum whose variants are the text ids in the file. The path is relative to the `CARGO_MANIFEST_DIR`.
+
+The enum is named `TextId` by default. Another name can be given in front of the path, e.g. `catalog!(Messages, "langs/en_US.json")`. The variant name of a text id is its upper camel case form, e.g. `page.title` becomes `PageTitle`. It is a compile error if two text ids become the same variant, or a text id becomes a name which cannot be a variant (e.g. `self` becomes `Self`).
+
+The enum implements `AsRef<str>`, so it can be used wherever a text id is accepted. A misspelled or removed text id becomes a compile error.
+
+```ignore
+json_gettext::catalog!("langs/en_US.json");
+
+assert_eq!("hello", TextId::Hello.as_str());
+```
+*/
+#[proc_macro]
+pub fn catalog(input: TokenStream) -> TokenStream {
+    catalog::catalog(input.into()).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+/**
+Read a JSON file at compile time and generate a module of functions, one for each string in the file. The path is relative to the `CARGO_MANIFEST_DIR`.
+
+The function name of a text id is its snake case form, e.g. `page.title` becomes `page_title`. Every `{name}` placeholder in the text becomes a parameter which accepts any `Display` value, so a missing argument becomes a compile error. Values which are not strings are skipped.
+
+A generated function looks up the text of a key from a `JSONGetText` instance and replaces the placeholders. If the text is not found or is not a string, the text from the JSON file is used.
+
+```ignore
+json_gettext::messages!(msgs, "langs/en_US.json");
+
+let s: String = msgs::welcome(&ctx, "en_US", "Len");
+```
+*/
+#[proc_macro]
+pub fn messages(input: TokenStream) -> TokenStream {
+    messages::messages(input.into()).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn verify_catalogs(input: TokenStream) -> TokenStream {
+    verify::verify_catalogs(input.into()).unwrap_or_else(|err| err.to_compile_error()).into()
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Token,
+};
+
+use crate::common::read_json_object;
+
+struct MessagesInput {
+    name: Ident,
+    path: LitStr,
+}
+
+impl Parse for MessagesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+
+        input.parse::<Token![,]>()?;
+
+        let path = input.parse()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(MessagesInput {
+            name,
+            path,
+        })
+    }
+}
+
+pub(crate) fn messages(input: TokenStream) -> syn::Result<TokenStream> {
+    let MessagesInput {
+        name,
+        path,
+    } = syn::parse2(input)?;
+
+    let (full_path, object) = read_json_object(&path)?;
+
+    let full_path = full_path.to_string_lossy().into_owned();
+
+    let mut texts: Vec<(&String, &str)> =
+        object.iter().filter_map(|(text, value)| value.as_str().map(|s| (text, s))).collect();
+
+    texts.sort_unstable_by_key(|(text, _)| *text);
+
+    let mut function_texts: HashMap<String, &str> = HashMap::with_capacity(texts.len());
+
+    let mut functions = Vec::with_capacity(texts.len());
+
+    for (text, template) in texts {
+        let function_name = to_snake_case(text);
+
+        if let Some(other) = function_texts.insert(function_name.clone(), text) {
+            return Err(syn::Error::new(
+                path.span(),
+                format!(
+                    "the text ids `{}` and `{}` both become the function `{}`",
+                    other, text, function_name
+                ),
+            ));
+        }
+
+        let function = to_ident(&function_name, path.span())?;
+
+        let placeholders = placeholders(template);
+
+        let mut params = Vec::with_capacity(placeholders.len());
+
+        for placeholder in placeholders.iter() {
+            let param = to_snake_case(placeholder);
+
+            if param == "ctx" || param == "key" {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!(
+                        "the placeholder `{{{}}}` of `{}` cannot be named `{}`",
+                        placeholder, text, param
+                    ),
+                ));
+            }
+
+            params.push(to_ident(&param, path.span())?);
+        }
+
+        let doc = format!("`{}`: {}", text, template);
+
+        functions.push(quote! {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            pub fn #function<K: ::json_gettext::LocaleKey>(
+                ctx: &::json_gettext::JSONGetText<'_, K>,
+                key: impl ::json_gettext::AsLocaleKey<K>,
+                #(#params: impl ::core::fmt::Display,)*
+            ) -> ::std::string::String {
+                ::json_gettext::format_message(
+                    ctx.get_text_with_key(key, #text),
+                    #template,
+                    &[#((#placeholders, &#params),)*],
+                )
+            }
+        });
+    }
+
+    Ok(quote! {
+        /// Message functions generated from a JSON file.
+        pub mod #name {
+            // rebuild when the JSON file changes
+            const _: &[u8] = include_bytes!(#full_path);
+
+            #(#functions)*
+        }
+    })
+}
+
+/// Get the distinct `{name}` placeholders of a text in order. `{{` and `}}` are escaped braces.
+fn placeholders(template: &str) -> Vec<String> {
+    let mut placeholders: Vec<String> = Vec::new();
+
+    let mut remaining = template;
+
+    while let Some(index) = remaining.find(['{', '}']) {
+        let after = &remaining[index..];
+
+        if after.starts_with("{{") || after.starts_with("}}") {
+            remaining = &after[2..];
+
+            continue;
+        }
+
+        if after.starts_with('{') {
+            if let Some(end) = after.find('}') {
+                let name = &after[1..end];
+
+                if is_placeholder_name(name) {
+                    if !placeholders.iter().any(|p| p == name) {
+                        placeholders.push(String::from(name));
+                    }
+
+                    remaining = &after[end + 1..];
+
+                    continue;
+                }
+            }
+        }
+
+        remaining = &after[1..];
+    }
+
+    placeholders
+}
+
+#[inline]
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Convert a text id or a placeholder to a snake case identifier, e.g. `page.title` to `page_title` and `userName` to `user_name`.
+fn to_snake_case(s: &str) -> String {
+    let mut name = String::with_capacity(s.len());
+
+    let mut separate = false;
+
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            if c.is_uppercase() {
+                if !name.is_empty() && !name.ends_with('_') {
+                    name.push('_');
+                }
+
+                name.extend(c.to_lowercase());
+            } else {
+                if separate && !name.is_empty() {
+                    name.push('_');
+                }
+
+                name.push(c);
+            }
+
+            separate = false;
+        } else {
+            separate = true;
+        }
+    }
+
+    if !name.starts_with(|c: char| c.is_alphabetic()) {
+        name.insert_str(0, "arg");
+    }
+
+    name
+}
+
+/// Create an identifier, which is raw if the name is a keyword. `self`, `Self`, `super` and `crate` cannot be raw identifiers, so they are errors.
+#[inline]
+fn to_ident(name: &str, span: Span) -> syn::Result<Ident> {
+    match syn::parse_str::<Ident>(name) {
+        Ok(_) => Ok(Ident::new(name, span)),
+        Err(_) if matches!(name, "self" | "Self" | "super" | "crate") => Err(syn::Error::new(
+            span,
+            format!("`{}` cannot be the name of a message function or a parameter", name),
+        )),
+        Err(_) => Ok(Ident::new_raw(name, span)),
+    }
+}
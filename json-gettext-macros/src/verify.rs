@@ -0,0 +1,267 @@
+use proc_macro2::TokenStream;
+use serde_json::{Map, Value};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Expr, ExprLit, Ident, Lit, LitStr, Token,
+};
+
+use crate::common::read_json_object;
+
+struct Catalog {
+    key:  Expr,
+    path: Expr,
+}
+
+impl Parse for Catalog {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+
+        input.parse::<Token![=>]>()?;
+
+        let path = input.parse()?;
+
+        Ok(Catalog {
+            key,
+            path,
+        })
+    }
+}
+
+struct VerifyInput {
+    strict:      bool,
+    default_key: Expr,
+    catalogs:    Punctuated<Catalog, Token![,]>,
+}
+
+impl Parse for VerifyInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut strict = false;
+
+        for attr in input.call(Attribute::parse_inner)? {
+            if attr.path().is_ident("strict") {
+                attr.meta.require_path_only()?;
+
+                strict = true;
+            } else {
+                return Err(syn::Error::new_spanned(attr, "the only option is `#![strict]`"));
+            }
+        }
+
+        let default_key = input.parse()?;
+
+        input.parse::<Token![;]>()?;
+
+        let catalogs = Punctuated::parse_terminated(input)?;
+
+        Ok(VerifyInput {
+            strict,
+            default_key,
+            catalogs,
+        })
+    }
+}
+
+/// Check that every JSON file can be parsed, no key is duplicated (keys are normalized like they are at runtime, so `en-us` and `en_US` are the same key), and every text in the JSON files also exists in the JSON file of the default key. With `#![strict]`, every text in the JSON file of the default key also has to exist in the JSON files of other keys.
+///
+/// Paths and the default key have to be string literals (the default key can also be a `key!` macro taking a string literal, such as `key!("en_US")` or `key!(langid: "en-US")`). Other keys which are not string literals cannot be compared, so they are not checked for duplication or completeness.
+pub(crate) fn verify_catalogs(input: TokenStream) -> syn::Result<TokenStream> {
+    let VerifyInput {
+        strict,
+        default_key,
+        catalogs,
+    } = syn::parse2(input)?;
+
+    let mut keys: Vec<Option<LitStr>> = Vec::with_capacity(catalogs.len());
+
+    // the keys are checked before any file is read
+    for catalog in catalogs.iter() {
+        let key = literal_str(&catalog.key);
+
+        if let Some(key) = key.as_ref() {
+            let normalized = normalize_key(&key.value());
+
+            if let Some(other) =
+                keys.iter().flatten().find(|other| normalize_key(&other.value()) == normalized)
+            {
+                return Err(syn::Error::new(
+                    key.span(),
+                    if other.value() == key.value() {
+                        format!("the key `{}` is duplicated", key.value())
+                    } else {
+                        format!(
+                            "the key `{}` is duplicated, because it is the same as `{}` after \
+                             being normalized to `{}`",
+                            key.value(),
+                            other.value(),
+                            normalized
+                        )
+                    },
+                ));
+            }
+        }
+
+        keys.push(key);
+    }
+
+    let mut files: Vec<(Option<LitStr>, LitStr, Map<String, Value>)> =
+        Vec::with_capacity(catalogs.len());
+
+    for (catalog, key) in catalogs.iter().zip(keys) {
+        let path = literal_str(&catalog.path).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &catalog.path,
+                "the path of a JSON file must be a string literal to be verified",
+            )
+        })?;
+
+        let (_, object) = read_json_object(&path)?;
+
+        files.push((key, path, object));
+    }
+
+    let default_key = literal_str(&default_key).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &default_key,
+            "the default key must be a string literal or a `key!` macro taking a string literal \
+             to verify the JSON files",
+        )
+    })?;
+
+    let normalized_default_key = normalize_key(&default_key.value());
+
+    let default_object = match files.iter().find(|(key, ..)| {
+        key.as_ref().map(|key| normalize_key(&key.value())).as_ref()
+            == Some(&normalized_default_key)
+    }) {
+        Some((_, _, object)) => object,
+        None => {
+            if strict || files.iter().all(|(key, ..)| key.is_some()) {
+                return Err(syn::Error::new(
+                    default_key.span(),
+                    format!("the default key `{}` has no JSON file", default_key.value()),
+                ));
+            }
+
+            return Ok(TokenStream::new());
+        },
+    };
+
+    let mut error: Option<syn::Error> = None;
+
+    let mut push_error = |e: syn::Error| match error.as_mut() {
+        Some(error) => error.combine(e),
+        None => error = Some(e),
+    };
+
+    for (key, path, object) in files.iter() {
+        if strict {
+            if let Some(key) =
+                key.as_ref().filter(|key| normalize_key(&key.value()) != normalized_default_key)
+            {
+                for text in default_object.keys() {
+                    if !object.contains_key(text) {
+                        push_error(syn::Error::new(
+                            path.span(),
+                            format!(
+                                "the text `{}` of the default key `{}` is not translated by the \
+                                 key `{}`",
+                                text,
+                                default_key.value(),
+                                key.value()
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for text in object.keys() {
+            if !default_object.contains_key(text) {
+                let message = match key {
+                    Some(key) => format!(
+                        "the text `{}` of the key `{}` does not exist in the default key `{}`",
+                        text,
+                        key.value(),
+                        default_key.value()
+                    ),
+                    None => format!(
+                        "the text `{}` in `{}` does not exist in the default key `{}`",
+                        text,
+                        path.value(),
+                        default_key.value()
+                    ),
+                };
+
+                push_error(syn::Error::new(path.span(), message));
+            }
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(TokenStream::new()),
+    }
+}
+
+/// Normalize a key like `LocaleKey::normalize` does for `String` keys in `json-gettext`, e.g. `en-us` becomes `en_US`, so that keys are compared as they are at runtime.
+fn normalize_key(key: &str) -> String {
+    let mut normalized = String::with_capacity(key.len());
+    let mut extension = false;
+
+    for (i, subtag) in key.split(['-', '_']).enumerate() {
+        if i > 0 {
+            normalized.push('_');
+        }
+
+        let is_alphabetic = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+
+        if i == 0 || extension || subtag.len() == 1 {
+            extension = extension || (i > 0 && subtag.len() == 1);
+
+            normalized.push_str(&subtag.to_ascii_lowercase());
+        } else if subtag.len() == 4 && is_alphabetic {
+            let (first, rest) = subtag.split_at(1);
+
+            normalized.push_str(&first.to_ascii_uppercase());
+            normalized.push_str(&rest.to_ascii_lowercase());
+        } else if (subtag.len() == 2 && is_alphabetic)
+            || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+        {
+            normalized.push_str(&subtag.to_ascii_uppercase());
+        } else {
+            normalized.push_str(&subtag.to_ascii_lowercase());
+        }
+    }
+
+    normalized
+}
+
+/// Get the string literal of an expression like `"en_US"`, `key!("en_US")` or `key!(langid: "en-US")`.
+fn literal_str(expr: &Expr) -> Option<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Some(s.clone()),
+        Expr::Group(group) => literal_str(&group.expr),
+        Expr::Paren(paren) => literal_str(&paren.expr),
+        Expr::Macro(mac) => mac.mac.parse_body_with(parse_key_macro_body).ok(),
+        _ => None,
+    }
+}
+
+/// Parse `"en_US"` or `langid: "en-US"`.
+fn parse_key_macro_body(input: ParseStream) -> syn::Result<LitStr> {
+    if input.peek(Ident) {
+        input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+    }
+
+    let s = input.parse()?;
+
+    if !input.is_empty() {
+        return Err(input.error("expected a string literal"));
+    }
+
+    Ok(s)
+}
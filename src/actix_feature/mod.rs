@@ -0,0 +1,123 @@
+use std::{
+    borrow::Cow,
+    future::{ready, Ready},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use actix_web::{
+    dev::Payload, error::ErrorInternalServerError, http::header, web, FromRequest, HttpRequest,
+};
+
+use crate::{
+    JSONGetText, JSONGetTextBuildError, JSONGetTextHandle, LocaleConfig, LocaleKey, LocaleRequest,
+    Translator,
+};
+
+/// A `JSONGetTextHandle` shared by the workers of an actix-web server, with the configuration of locale negotiation. Register it by `App::app_data(web::Data::new(...))`, and then [`Translator`] can be extracted. In debug builds (without the `embed` feature), the json files are reloaded when they are modified.
+#[derive(Debug)]
+pub struct JSONGetTextData<K: LocaleKey = String> {
+    handle:   RwLock<JSONGetTextHandle<K>>,
+    embedded: bool,
+    config:   LocaleConfig,
+}
+
+impl<K: LocaleKey> JSONGetTextData<K> {
+    /// Create a `JSONGetTextData` instance with the default `LocaleConfig`.
+    #[inline]
+    pub fn new(handle: JSONGetTextHandle<K>) -> JSONGetTextData<K> {
+        JSONGetTextData {
+            embedded: handle.is_embedded(),
+            handle:   RwLock::new(handle),
+            config:   LocaleConfig::default(),
+        }
+    }
+
+    /// Set the sources of locales.
+    #[inline]
+    pub fn with_config(mut self, config: LocaleConfig) -> Self {
+        self.config = config;
+
+        self
+    }
+
+    #[inline]
+    pub fn get_config(&self) -> &LocaleConfig {
+        &self.config
+    }
+
+    /// Get the current `JSONGetText` instance. The json files are reloaded first if they are modified and not embedded. They are checked under the read lock, so the write lock is only taken when they need to be reloaded. If the reloading fails, the previous context is used, and the error is logged by the `log` facade or the `tracing` facade if either feature is enabled.
+    pub fn get(&self) -> Arc<JSONGetText<'static, K>> {
+        if !self.embedded {
+            let modified = self.handle.read().unwrap_or_else(PoisonError::into_inner).is_modified();
+
+            // an IO error is reported by reloading
+            if !matches!(modified, Ok(false)) {
+                if let Err(_err) = self.reload_if_needed() {
+                    #[cfg(feature = "log")]
+                    log::error!("Failed to reload the JSONGetText context: {}", _err);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(error = %_err, "failed to reload the JSONGetText context");
+                }
+            }
+        }
+
+        self.handle.read().unwrap_or_else(PoisonError::into_inner).share()
+    }
+
+    /// Reload the json files if any of them has been modified. See [`JSONGetTextHandle::reload_if_needed`]. The write lock is taken, so prefer [`get`](Self::get), which checks the files under the read lock first.
+    #[inline]
+    pub fn reload_if_needed(&self) -> Result<bool, JSONGetTextBuildError> {
+        self.handle.write().unwrap_or_else(PoisonError::into_inner).reload_if_needed()
+    }
+}
+
+impl<K: LocaleKey> FromRequest for Translator<K> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let data = match req.app_data::<web::Data<JSONGetTextData<K>>>() {
+            Some(data) => data,
+            None => {
+                return ready(Err(ErrorInternalServerError("JSONGetTextData is not registered.")))
+            },
+        };
+
+        let ctx = data.get();
+
+        let (key, source) = data.config.negotiate(&ctx, req);
+
+        let source = source.cloned();
+
+        ready(Ok(Translator::new(ctx, key).with_source(source)))
+    }
+}
+
+impl LocaleRequest for HttpRequest {
+    #[inline]
+    fn path_segment(&self, index: usize) -> Option<Cow<'_, str>> {
+        crate::locale_negotiation::path_segment(self.path(), index).map(Cow::Borrowed)
+    }
+
+    #[inline]
+    fn query_value(&self, name: &str) -> Option<Cow<'_, str>> {
+        crate::locale_negotiation::query_value(self.query_string(), name)
+    }
+
+    #[inline]
+    fn cookie(&self, name: &str) -> Option<Cow<'_, str>> {
+        let cookie_headers =
+            self.headers().get_all(header::COOKIE).filter_map(|value| value.to_str().ok());
+
+        crate::locale_negotiation::find_cookie(cookie_headers, name).map(Cow::Borrowed)
+    }
+
+    #[inline]
+    fn accept_language(&self) -> Option<Cow<'_, str>> {
+        self.headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(Cow::Borrowed)
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    borrow::Cow,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, Request, StatusCode, Uri},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{JSONGetText, LocaleConfig, LocaleKey, LocaleRequest, Translator};
+
+/// A Tower layer which negotiates the locale of every request from the sources in a [`LocaleConfig`], and inserts a [`Translator`] bound to the locale into the request extensions, so handlers can extract it.
+#[derive(Debug)]
+pub struct JSONGetTextLayer<K: LocaleKey = String> {
+    ctx:    Arc<JSONGetText<'static, K>>,
+    config: Arc<LocaleConfig>,
+}
+
+impl<K: LocaleKey> JSONGetTextLayer<K> {
+    /// Create a `JSONGetTextLayer` instance with the default `LocaleConfig`.
+    #[inline]
+    pub fn new<C: Into<Arc<JSONGetText<'static, K>>>>(ctx: C) -> JSONGetTextLayer<K> {
+        JSONGetTextLayer {
+            ctx: ctx.into(), config: Arc::new(LocaleConfig::default())
+        }
+    }
+
+    /// Set the sources of locales.
+    #[inline]
+    pub fn with_config(mut self, config: LocaleConfig) -> Self {
+        self.config = Arc::new(config);
+
+        self
+    }
+}
+
+impl<K: LocaleKey> Clone for JSONGetTextLayer<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        JSONGetTextLayer {
+            ctx: self.ctx.clone(), config: self.config.clone()
+        }
+    }
+}
+
+impl<S, K: LocaleKey> Layer<S> for JSONGetTextLayer<K> {
+    type Service = JSONGetTextService<S, K>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        JSONGetTextService {
+            inner,
+            ctx: self.ctx.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// The service created by [`JSONGetTextLayer`].
+#[derive(Debug)]
+pub struct JSONGetTextService<S, K: LocaleKey = String> {
+    inner:  S,
+    ctx:    Arc<JSONGetText<'static, K>>,
+    config: Arc<LocaleConfig>,
+}
+
+impl<S: Clone, K: LocaleKey> Clone for JSONGetTextService<S, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        JSONGetTextService {
+            inner:  self.inner.clone(),
+            ctx:    self.ctx.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+
+impl<S, B, K: LocaleKey> Service<Request<B>> for JSONGetTextService<S, K>
+where
+    S: Service<Request<B>>,
+{
+    type Error = S::Error;
+    type Future = S::Future;
+    type Response = S::Response;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let (key, source) = self
+            .config
+            .negotiate(&self.ctx, &HttpRequest {
+                uri: req.uri(), headers: req.headers()
+            });
+
+        let translator = Translator::new(self.ctx.clone(), key).with_source(source.cloned());
+
+        req.extensions_mut().insert(translator);
+
+        self.inner.call(req)
+    }
+}
+
+impl<S: Send + Sync, K: LocaleKey> FromRequestParts<S> for Translator<K> {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Translator<K>>()
+            .cloned()
+            .ok_or((StatusCode::INTERNAL_SERVER_ERROR, "JSONGetTextLayer is not added."))
+    }
+}
+
+struct HttpRequest<'r> {
+    uri:     &'r Uri,
+    headers: &'r HeaderMap,
+}
+
+impl<'r> LocaleRequest for HttpRequest<'r> {
+    #[inline]
+    fn path_segment(&self, index: usize) -> Option<Cow<'_, str>> {
+        crate::locale_negotiation::path_segment(self.uri.path(), index).map(Cow::Borrowed)
+    }
+
+    #[inline]
+    fn query_value(&self, name: &str) -> Option<Cow<'_, str>> {
+        crate::locale_negotiation::query_value(self.uri.query()?, name)
+    }
+
+    #[inline]
+    fn cookie(&self, name: &str) -> Option<Cow<'_, str>> {
+        let cookie_headers =
+            self.headers.get_all(header::COOKIE).iter().filter_map(|value| value.to_str().ok());
+
+        crate::locale_negotiation::find_cookie(cookie_headers, name).map(Cow::Borrowed)
+    }
+
+    #[inline]
+    fn accept_language(&self) -> Option<Cow<'_, str>> {
+        self.headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(Cow::Borrowed)
+    }
+}
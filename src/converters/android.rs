@@ -0,0 +1,336 @@
+use std::{collections::HashMap, fmt::Write};
+
+use quick_xml::{
+    escape::{escape, unescape},
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{JSONGetTextFormatError, PLURAL_CATEGORIES};
+use crate::{
+    serde_json::{Map, Value},
+    JSONGetTextMap, JSONGetTextValue,
+};
+
+/// Parse an Android `strings.xml` resource document into a text map.
+///
+/// * `<string>` becomes a string.
+/// * `<string-array>` becomes an array of strings.
+/// * `<plurals>` becomes an object whose keys are plural categories (`zero`, `one`, `two`, `few`, `many`, `other`).
+///
+/// Strings marked with `translatable="false"` are included as well.
+pub fn parse_android_strings_xml<S: AsRef<str>>(
+    xml: S,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    let mut reader = Reader::from_str(xml.as_ref());
+
+    let mut map = HashMap::new();
+
+    let mut in_resources = false;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.name().as_ref() {
+                b"resources" if !in_resources => in_resources = true,
+                b"string" if in_resources => {
+                    let name = get_name(&e)?;
+                    let text = read_android_text(&mut reader, &e)?;
+
+                    map.insert(name, JSONGetTextValue::from_string(text));
+                },
+                b"string-array" if in_resources => {
+                    let name = get_name(&e)?;
+
+                    let mut array = Vec::new();
+
+                    for_each_item(&mut reader, &e, |item, text| {
+                        if item.name().as_ref() == b"item" {
+                            array.push(Value::String(text));
+                        }
+
+                        Ok(())
+                    })?;
+
+                    map.insert(name, JSONGetTextValue::from_json_value(Value::Array(array)));
+                },
+                b"plurals" if in_resources => {
+                    let name = get_name(&e)?;
+
+                    let mut object = Map::new();
+
+                    for_each_item(&mut reader, &e, |item, text| {
+                        if item.name().as_ref() == b"item" {
+                            let quantity = get_attribute(item, b"quantity")?.ok_or_else(|| {
+                                JSONGetTextFormatError::InvalidFormat(format!(
+                                    "An item of the plurals `{}` has no quantity.",
+                                    name
+                                ))
+                            })?;
+
+                            if !PLURAL_CATEGORIES.contains(&quantity.as_str()) {
+                                return Err(JSONGetTextFormatError::InvalidFormat(format!(
+                                    "`{}` is not a plural category.",
+                                    quantity
+                                )));
+                            }
+
+                            object.insert(quantity, Value::String(text));
+                        }
+
+                        Ok(())
+                    })?;
+
+                    map.insert(name, JSONGetTextValue::from_json_value(Value::Object(object)));
+                },
+                _ if in_resources => {
+                    reader.read_to_end(e.name())?;
+                },
+                _ => {
+                    return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                        "The root element must be `resources`.",
+                    )));
+                },
+            },
+            Event::Empty(e) if in_resources => match e.name().as_ref() {
+                b"string" => {
+                    map.insert(get_name(&e)?, JSONGetTextValue::from_string(""));
+                },
+                b"string-array" => {
+                    map.insert(
+                        get_name(&e)?,
+                        JSONGetTextValue::from_json_value(Value::Array(Vec::new())),
+                    );
+                },
+                _ => (),
+            },
+            Event::End(_) => in_resources = false,
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+
+    Ok(map)
+}
+
+/// Generate an Android `strings.xml` resource document from a text map. Strings, arrays of strings and plural objects are supported.
+pub fn to_android_strings_xml<'b, 'a: 'b, M: Into<JSONGetTextMap<'b, 'a>>>(
+    map: M,
+) -> Result<String, JSONGetTextFormatError> {
+    let mut names: Vec<(&str, &JSONGetTextValue)> = map.into().iter().collect();
+
+    names.sort_unstable_by_key(|(name, _)| *name);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+
+    for (name, value) in names {
+        let unsupported = || JSONGetTextFormatError::UnsupportedValue {
+            text: String::from(name)
+        };
+
+        let name = escape(name);
+
+        if let Some(s) = value.as_str() {
+            writeln!(xml, "    <string name=\"{}\">{}</string>", name, escape_android_text(s))
+                .unwrap();
+
+            continue;
+        }
+
+        match value.to_json_value().ok_or_else(unsupported)?.as_ref() {
+            Value::Array(array) => {
+                writeln!(xml, "    <string-array name=\"{}\">", name).unwrap();
+
+                for item in array {
+                    let item = item.as_str().ok_or_else(unsupported)?;
+
+                    writeln!(xml, "        <item>{}</item>", escape_android_text(item)).unwrap();
+                }
+
+                xml.push_str("    </string-array>\n");
+            },
+            Value::Object(object) if super::is_plural_object(object) => {
+                writeln!(xml, "    <plurals name=\"{}\">", name).unwrap();
+
+                for quantity in PLURAL_CATEGORIES {
+                    if let Some(item) = object.get(quantity) {
+                        writeln!(
+                            xml,
+                            "        <item quantity=\"{}\">{}</item>",
+                            quantity,
+                            escape_android_text(item.as_str().unwrap())
+                        )
+                        .unwrap();
+                    }
+                }
+
+                xml.push_str("    </plurals>\n");
+            },
+            _ => return Err(unsupported()),
+        }
+    }
+
+    xml.push_str("</resources>\n");
+
+    Ok(xml)
+}
+
+#[inline]
+fn get_attribute(e: &BytesStart, name: &[u8]) -> Result<Option<String>, JSONGetTextFormatError> {
+    match e.try_get_attribute(name)? {
+        Some(attribute) => Ok(Some(attribute.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+#[inline]
+fn get_name(e: &BytesStart) -> Result<String, JSONGetTextFormatError> {
+    get_attribute(e, b"name")?.ok_or_else(|| {
+        JSONGetTextFormatError::InvalidFormat(format!(
+            "The `{}` element has no name.",
+            String::from_utf8_lossy(e.name().as_ref())
+        ))
+    })
+}
+
+fn for_each_item<F>(
+    reader: &mut Reader<&[u8]>,
+    parent: &BytesStart,
+    mut f: F,
+) -> Result<(), JSONGetTextFormatError>
+where
+    F: FnMut(&BytesStart, String) -> Result<(), JSONGetTextFormatError>, {
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                let text = read_android_text(reader, &e)?;
+
+                f(&e, text)?;
+            },
+            Event::Empty(e) => f(&e, String::new())?,
+            Event::End(e) if e.name() == parent.name() => return Ok(()),
+            Event::Eof => {
+                return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                    "Unexpected end of the document.",
+                )))
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Read the inner content of an element. Inline markup such as `<b>` is kept as it is.
+fn read_android_text(
+    reader: &mut Reader<&[u8]>,
+    e: &BytesStart,
+) -> Result<String, JSONGetTextFormatError> {
+    let raw = reader.read_text(e.name())?;
+
+    let mut xml_decoded = String::with_capacity(raw.len());
+
+    let mut remaining = raw.as_ref();
+
+    while let Some(index) = remaining.find("<![CDATA[") {
+        xml_decoded.push_str(&unescape(&remaining[..index])?);
+
+        let cdata = &remaining[index + 9..];
+
+        let end = cdata.find("]]>").ok_or_else(|| {
+            JSONGetTextFormatError::InvalidFormat(String::from("A CDATA section is not closed."))
+        })?;
+
+        xml_decoded.push_str(&cdata[..end]);
+
+        remaining = &cdata[end + 3..];
+    }
+
+    xml_decoded.push_str(&unescape(remaining)?);
+
+    Ok(unescape_android_text(&xml_decoded))
+}
+
+/// Resolve the backslash escapes and the double quotes of an Android string resource. Whitespace outside double quotes is collapsed.
+fn unescape_android_text(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+
+    let mut chars = s.trim().chars();
+
+    let mut quoted = false;
+    let mut last_is_space = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                last_is_space = false;
+
+                match chars.next() {
+                    Some('n') => result.push('\n'),
+                    Some('t') => result.push('\t'),
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).collect();
+
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(c) => result.push(c),
+                            None => {
+                                result.push_str("\\u");
+                                result.push_str(&hex);
+                            },
+                        }
+                    },
+                    Some(c) => result.push(c),
+                    None => result.push('\\'),
+                }
+            },
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !last_is_space {
+                    result.push(' ');
+
+                    last_is_space = true;
+                }
+            },
+            c => {
+                last_is_space = false;
+
+                result.push(c);
+            },
+        }
+    }
+
+    result
+}
+
+/// Escape a string for an Android string resource. XML special characters are escaped as well.
+fn escape_android_text(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+
+    let need_quotes =
+        s.starts_with(char::is_whitespace) || s.ends_with(char::is_whitespace) || s.contains("  ");
+
+    if need_quotes {
+        result.push('"');
+    }
+
+    for (i, c) in s.chars().enumerate() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\'' => result.push_str("\\'"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '@' | '?' if i == 0 => {
+                result.push('\\');
+                result.push(c);
+            },
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            _ => result.push(c),
+        }
+    }
+
+    if need_quotes {
+        result.push('"');
+    }
+
+    result
+}
@@ -0,0 +1,539 @@
+use std::{collections::HashMap, fmt::Write, iter::Peekable, str::Chars};
+
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{JSONGetTextFormatError, PLURAL_CATEGORIES};
+use crate::{
+    serde_json::{Map, Number, Value},
+    JSONGetTextMap, JSONGetTextValue,
+};
+
+const FORMAT_KEY: &str = "NSStringLocalizedFormatKey";
+const SPEC_TYPE_KEY: &str = "NSStringFormatSpecTypeKey";
+const VALUE_TYPE_KEY: &str = "NSStringFormatValueTypeKey";
+const PLURAL_RULE_TYPE: &str = "NSStringPluralRuleType";
+const PLURAL_VARIABLE: &str = "value";
+const PLURAL_VALUE_TYPE: &str = "d";
+
+/// Parse an Apple `.strings` document into a text map.
+pub fn parse_apple_strings<S: AsRef<str>>(
+    s: S,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    let mut chars = s.as_ref().chars().peekable();
+
+    let mut map = HashMap::new();
+
+    loop {
+        skip_whitespace_and_comments(&mut chars)?;
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = read_strings_token(&mut chars)?;
+
+        skip_whitespace_and_comments(&mut chars)?;
+
+        let value = match chars.next() {
+            Some('=') => {
+                skip_whitespace_and_comments(&mut chars)?;
+
+                let value = read_strings_token(&mut chars)?;
+
+                skip_whitespace_and_comments(&mut chars)?;
+
+                if chars.next() != Some(';') {
+                    return Err(expected(";", &key));
+                }
+
+                value
+            },
+            Some(';') => key.clone(),
+            _ => return Err(expected("=", &key)),
+        };
+
+        map.insert(key, JSONGetTextValue::from_string(value));
+    }
+
+    Ok(map)
+}
+
+/// Generate an Apple `.strings` document from a text map. Plural objects are skipped because they belong to `.stringsdict` documents.
+pub fn to_apple_strings<'b, 'a: 'b, M: Into<JSONGetTextMap<'b, 'a>>>(
+    map: M,
+) -> Result<String, JSONGetTextFormatError> {
+    let mut keys: Vec<(&str, &JSONGetTextValue)> = map.into().iter().collect();
+
+    keys.sort_unstable_by_key(|(key, _)| *key);
+
+    let mut s = String::new();
+
+    for (key, value) in keys {
+        match value.as_str() {
+            Some(value) => {
+                writeln!(s, "\"{}\" = \"{}\";", escape_strings(key), escape_strings(value))
+                    .unwrap();
+            },
+            None => match value.to_json_value().as_deref() {
+                Some(Value::Object(_)) => continue,
+                _ => {
+                    return Err(JSONGetTextFormatError::UnsupportedValue {
+                        text: String::from(key),
+                    })
+                },
+            },
+        }
+    }
+
+    Ok(s)
+}
+
+/// Parse an Apple `.stringsdict` document into a text map.
+///
+/// An entry which has only one plural variable becomes an object whose keys are plural categories (`zero`, `one`, `two`, `few`, `many`, `other`). If the name of the variable is not `value`, the object keeps `NSStringLocalizedFormatKey`, and if the value type of the variable is not `d` (e.g. `lu` or `@`), the object keeps `NSStringFormatValueTypeKey`. Other entries are converted from their property lists as they are.
+pub fn parse_apple_stringsdict<S: AsRef<str>>(
+    xml: S,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    let mut reader = Reader::from_str(xml.as_ref());
+
+    let root = loop {
+        match reader.read_event()? {
+            Event::Start(e) if e.name().as_ref() == b"plist" => {
+                match next_plist_value(&mut reader)? {
+                    Some(value) => break value,
+                    None => break Value::Object(Map::new()),
+                }
+            },
+            Event::Start(_) | Event::Empty(_) | Event::Eof => {
+                return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                    "The root element must be `plist`.",
+                )));
+            },
+            _ => (),
+        }
+    };
+
+    let root = match root {
+        Value::Object(root) => root,
+        _ => {
+            return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                "The root value must be a dictionary.",
+            )))
+        },
+    };
+
+    let mut map = HashMap::with_capacity(root.len());
+
+    for (key, entry) in root {
+        let value = match flatten_plural_entry(&entry) {
+            Some(plural) => Value::Object(plural),
+            None => entry,
+        };
+
+        map.insert(key, JSONGetTextValue::from_json_value(value));
+    }
+
+    Ok(map)
+}
+
+/// Generate an Apple `.stringsdict` document from a text map. Only plural objects and entries which have the `NSStringLocalizedFormatKey` key are included. The plural variable of a plural object is named `value` and has the `d` value type unless the object has `NSStringLocalizedFormatKey` or `NSStringFormatValueTypeKey`.
+pub fn to_apple_stringsdict<'b, 'a: 'b, M: Into<JSONGetTextMap<'b, 'a>>>(
+    map: M,
+) -> Result<String, JSONGetTextFormatError> {
+    let mut keys: Vec<(&str, &JSONGetTextValue)> = map.into().iter().collect();
+
+    keys.sort_unstable_by_key(|(key, _)| *key);
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist PUBLIC \"-//Apple//DTD \
+         PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<plist \
+         version=\"1.0\">\n<dict>\n",
+    );
+
+    for (key, value) in keys {
+        let json = value.to_json_value();
+
+        let object = match json.as_deref() {
+            Some(Value::Object(object)) => object,
+            _ => continue,
+        };
+
+        let entry = if super::is_plural_object(object) {
+            let format = object.get(FORMAT_KEY).and_then(|v| v.as_str());
+
+            let variable_name = match format {
+                Some(format) => plural_variable_name(format).ok_or_else(|| {
+                    JSONGetTextFormatError::UnsupportedValue {
+                        text: String::from(key)
+                    }
+                })?,
+                None => PLURAL_VARIABLE,
+            };
+
+            let value_type =
+                object.get(VALUE_TYPE_KEY).and_then(|v| v.as_str()).unwrap_or(PLURAL_VALUE_TYPE);
+
+            let mut variable = Map::new();
+
+            variable.insert(String::from(SPEC_TYPE_KEY), Value::from(PLURAL_RULE_TYPE));
+            variable.insert(String::from(VALUE_TYPE_KEY), Value::from(value_type));
+
+            for quantity in PLURAL_CATEGORIES {
+                if let Some(item) = object.get(quantity) {
+                    variable.insert(String::from(quantity), item.clone());
+                }
+            }
+
+            let mut entry = Map::new();
+
+            entry.insert(String::from(FORMAT_KEY), Value::from(format!("%#@{}@", variable_name)));
+            entry.insert(String::from(variable_name), Value::Object(variable));
+
+            entry
+        } else if object.contains_key(FORMAT_KEY) {
+            object.clone()
+        } else {
+            continue;
+        };
+
+        writeln!(xml, "    <key>{}</key>", escape(key)).unwrap();
+
+        write_plist_value(&mut xml, &Value::Object(entry), 1).map_err(|_| {
+            JSONGetTextFormatError::UnsupportedValue {
+                text: String::from(key)
+            }
+        })?;
+    }
+
+    xml.push_str("</dict>\n</plist>\n");
+
+    Ok(xml)
+}
+
+#[inline]
+fn expected(token: &str, key: &str) -> JSONGetTextFormatError {
+    JSONGetTextFormatError::InvalidFormat(format!("Expected `{}` after `{}`.", token, key))
+}
+
+fn skip_whitespace_and_comments(chars: &mut Peekable<Chars>) -> Result<(), JSONGetTextFormatError> {
+    loop {
+        match chars.peek() {
+            Some(c) if c.is_whitespace() => {
+                chars.next();
+            },
+            Some('/') => {
+                chars.next();
+
+                match chars.next() {
+                    Some('/') => {
+                        for c in chars.by_ref() {
+                            if c == '\n' {
+                                break;
+                            }
+                        }
+                    },
+                    Some('*') => {
+                        let mut last = '\0';
+
+                        loop {
+                            match chars.next() {
+                                Some('/') if last == '*' => break,
+                                Some(c) => last = c,
+                                None => {
+                                    return Err(JSONGetTextFormatError::InvalidFormat(
+                                        String::from("A comment is not closed."),
+                                    ))
+                                },
+                            }
+                        }
+                    },
+                    _ => {
+                        return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                            "Unexpected `/`.",
+                        )))
+                    },
+                }
+            },
+            _ => return Ok(()),
+        }
+    }
+}
+
+fn read_strings_token(chars: &mut Peekable<Chars>) -> Result<String, JSONGetTextFormatError> {
+    let mut s = String::new();
+
+    if chars.peek() != Some(&'"') {
+        while let Some(&c) = chars.peek() {
+            if c.is_alphanumeric() || "_.-$:/".contains(c) {
+                s.push(c);
+
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        return if s.is_empty() {
+            Err(JSONGetTextFormatError::InvalidFormat(String::from("Expected a string.")))
+        } else {
+            Ok(s)
+        };
+    }
+
+    chars.next();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => match chars.next() {
+                Some('n') => s.push('\n'),
+                Some('t') => s.push('\t'),
+                Some('r') => s.push('\r'),
+                Some('0') => s.push('\0'),
+                Some('U') | Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| {
+                            JSONGetTextFormatError::InvalidFormat(format!(
+                                "`{}` is not a valid Unicode escape.",
+                                hex
+                            ))
+                        })?;
+
+                    s.push(c);
+                },
+                Some(c) => s.push(c),
+                None => break,
+            },
+            Some(c) => s.push(c),
+            None => break,
+        }
+    }
+
+    Err(JSONGetTextFormatError::InvalidFormat(String::from("A string is not closed.")))
+}
+
+fn escape_strings(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[inline]
+fn plural_variable_name(format: &str) -> Option<&str> {
+    format.strip_prefix("%#@")?.strip_suffix('@').filter(|name| !name.is_empty())
+}
+
+fn flatten_plural_entry(entry: &Value) -> Option<Map<String, Value>> {
+    let entry = entry.as_object()?;
+
+    if entry.len() != 2 {
+        return None;
+    }
+
+    let variable_name = plural_variable_name(entry.get(FORMAT_KEY)?.as_str()?)?;
+
+    let variable = entry.get(variable_name)?.as_object()?;
+
+    if variable.get(SPEC_TYPE_KEY)?.as_str()? != PLURAL_RULE_TYPE {
+        return None;
+    }
+
+    let mut plural = Map::new();
+
+    for quantity in PLURAL_CATEGORIES {
+        if let Some(item) = variable.get(quantity) {
+            plural.insert(String::from(quantity), Value::String(item.as_str()?.to_string()));
+        }
+    }
+
+    if plural.is_empty() {
+        return None;
+    }
+
+    if variable_name != PLURAL_VARIABLE {
+        plural.insert(String::from(FORMAT_KEY), Value::from(format!("%#@{}@", variable_name)));
+    }
+
+    if let Some(value_type) = variable.get(VALUE_TYPE_KEY) {
+        let value_type = value_type.as_str()?;
+
+        if value_type != PLURAL_VALUE_TYPE {
+            plural.insert(String::from(VALUE_TYPE_KEY), Value::from(value_type));
+        }
+    }
+
+    Some(plural)
+}
+
+/// Read the next value of a property list. `None` is returned if the parent element ends.
+fn next_plist_value(reader: &mut Reader<&[u8]>) -> Result<Option<Value>, JSONGetTextFormatError> {
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => return read_plist_value(reader, &e).map(Some),
+            Event::Empty(e) => {
+                let value = match e.name().as_ref() {
+                    b"true" => Value::Bool(true),
+                    b"false" => Value::Bool(false),
+                    b"dict" => Value::Object(Map::new()),
+                    b"array" => Value::Array(Vec::new()),
+                    b"string" | b"data" | b"date" => Value::String(String::new()),
+                    _ => return Err(unexpected_element(&e)),
+                };
+
+                return Ok(Some(value));
+            },
+            Event::End(_) => return Ok(None),
+            Event::Eof => {
+                return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                    "Unexpected end of the document.",
+                )))
+            },
+            _ => (),
+        }
+    }
+}
+
+fn read_plist_value(
+    reader: &mut Reader<&[u8]>,
+    e: &BytesStart,
+) -> Result<Value, JSONGetTextFormatError> {
+    match e.name().as_ref() {
+        b"dict" => {
+            let mut map = Map::new();
+
+            loop {
+                let key = match next_plist_value(reader)? {
+                    Some(Value::String(key)) => key,
+                    Some(_) => {
+                        return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                            "Expected a `key` element in a `dict` element.",
+                        )))
+                    },
+                    None => break,
+                };
+
+                let value = next_plist_value(reader)?.ok_or_else(|| {
+                    JSONGetTextFormatError::InvalidFormat(format!(
+                        "The key `{}` has no value.",
+                        key
+                    ))
+                })?;
+
+                map.insert(key, value);
+            }
+
+            Ok(Value::Object(map))
+        },
+        b"array" => {
+            let mut array = Vec::new();
+
+            while let Some(value) = next_plist_value(reader)? {
+                array.push(value);
+            }
+
+            Ok(Value::Array(array))
+        },
+        b"key" | b"string" | b"data" | b"date" => Ok(Value::String(read_plist_text(reader, e)?)),
+        b"integer" => {
+            let text = read_plist_text(reader, e)?;
+
+            text.trim().parse::<i64>().map(Value::from).map_err(|_| {
+                JSONGetTextFormatError::InvalidFormat(format!("`{}` is not an integer.", text))
+            })
+        },
+        b"real" => {
+            let text = read_plist_text(reader, e)?;
+
+            text.trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(|| {
+                    JSONGetTextFormatError::InvalidFormat(format!(
+                        "`{}` is not a real number.",
+                        text
+                    ))
+                })
+        },
+        _ => Err(unexpected_element(e)),
+    }
+}
+
+#[inline]
+fn read_plist_text(
+    reader: &mut Reader<&[u8]>,
+    e: &BytesStart,
+) -> Result<String, JSONGetTextFormatError> {
+    let raw = reader.read_text(e.name())?;
+
+    Ok(quick_xml::escape::unescape(&raw)?.into_owned())
+}
+
+#[inline]
+fn unexpected_element(e: &BytesStart) -> JSONGetTextFormatError {
+    JSONGetTextFormatError::InvalidFormat(format!(
+        "Unexpected element `{}`.",
+        String::from_utf8_lossy(e.name().as_ref())
+    ))
+}
+
+fn write_plist_value(xml: &mut String, value: &Value, depth: usize) -> Result<(), ()> {
+    let indent = "    ".repeat(depth);
+
+    match value {
+        Value::Null => return Err(()),
+        Value::Bool(b) => writeln!(xml, "{}<{}/>", indent, b).unwrap(),
+        Value::Number(n) => {
+            if n.is_f64() {
+                writeln!(xml, "{}<real>{}</real>", indent, n).unwrap();
+            } else {
+                writeln!(xml, "{}<integer>{}</integer>", indent, n).unwrap();
+            }
+        },
+        Value::String(s) => {
+            writeln!(xml, "{}<string>{}</string>", indent, escape(s.as_str())).unwrap()
+        },
+        Value::Array(array) => {
+            writeln!(xml, "{}<array>", indent).unwrap();
+
+            for value in array {
+                write_plist_value(xml, value, depth + 1)?;
+            }
+
+            writeln!(xml, "{}</array>", indent).unwrap();
+        },
+        Value::Object(object) => {
+            writeln!(xml, "{}<dict>", indent).unwrap();
+
+            for (key, value) in object {
+                writeln!(xml, "{}    <key>{}</key>", indent, escape(key)).unwrap();
+
+                write_plist_value(xml, value, depth + 1)?;
+            }
+
+            writeln!(xml, "{}</dict>", indent).unwrap();
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use super::JSONGetTextFormatError;
+use crate::{
+    serde_json::{self, Map, Value},
+    JSONGetTextValue,
+};
+
+/// How to treat the `@`-prefixed metadata entries of an ARB document when it is added to a builder.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ARBMetadata {
+    /// Drop all metadata entries.
+    Strip,
+    /// Keep the `description` of a `@text` metadata entry as the description of the `text` text, see [`JSONGetText::get_description`](crate::JSONGetText::get_description). Other metadata is dropped.
+    Description,
+}
+
+impl Default for ARBMetadata {
+    #[inline]
+    fn default() -> Self {
+        ARBMetadata::Strip
+    }
+}
+
+/// Parse a Flutter Application Resource Bundle (ARB) document into a text map.
+///
+/// Global `@@`-prefixed entries such as `@@locale` and `@`-prefixed metadata entries are dropped. Messages are kept as they are. Use [`parse_arb_descriptions`] to get the descriptions in the metadata entries.
+#[inline]
+pub fn parse_arb<S: AsRef<str>>(
+    json: S,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    parse_arb_document(json, None)
+}
+
+/// Parse the descriptions in the `@`-prefixed metadata entries of a Flutter Application Resource Bundle (ARB) document into a map whose keys are the texts they describe, e.g. the description of `@hello` belongs to `hello`.
+#[inline]
+pub fn parse_arb_descriptions<S: AsRef<str>>(
+    json: S,
+) -> Result<HashMap<String, String>, JSONGetTextFormatError> {
+    let mut descriptions = HashMap::new();
+
+    parse_arb_document(json, Some(&mut descriptions))?;
+
+    Ok(descriptions)
+}
+
+/// Parse an ARB document into a text map, and collect its descriptions if `descriptions` is given.
+pub(crate) fn parse_arb_document<S: AsRef<str>>(
+    json: S,
+    mut descriptions: Option<&mut HashMap<String, String>>,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    let value: Map<String, Value> = serde_json::from_str(json.as_ref())
+        .map_err(|err| JSONGetTextFormatError::InvalidFormat(err.to_string()))?;
+
+    let mut map = HashMap::with_capacity(value.len());
+
+    for (k, v) in value {
+        if k.starts_with("@@") {
+            continue;
+        }
+
+        if let Some(text) = k.strip_prefix('@') {
+            if let Some(descriptions) = descriptions.as_deref_mut() {
+                if let Some(Value::String(description)) = v.get("description") {
+                    descriptions.insert(String::from(text), description.clone());
+                }
+            }
+
+            continue;
+        }
+
+        map.insert(k, JSONGetTextValue::from_json_value(v));
+    }
+
+    Ok(map)
+}
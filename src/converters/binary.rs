@@ -0,0 +1,389 @@
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    io::{self, Write},
+    str,
+};
+
+use super::JSONGetTextFormatError;
+use crate::{
+    serde_json::{Number, Value},
+    JSONGetTextMap, JSONGetTextValue, JSONTree, JSONTreeStr,
+};
+
+/// The magic number at the beginning of every binary catalog.
+pub const BINARY_CATALOG_MAGIC: [u8; 4] = *b"JGTC";
+
+/// The version of the binary catalog format written by this crate.
+pub const BINARY_CATALOG_VERSION: u16 = 2;
+
+const HEADER_LENGTH: usize = 4 + 2 + 2 + 8 + 8;
+
+const TAG_STRING: u8 = 0;
+const TAG_TREE: u8 = 1;
+
+const NODE_NULL: u8 = 0;
+const NODE_FALSE: u8 = 1;
+const NODE_TRUE: u8 = 2;
+const NODE_NUMBER: u8 = 3;
+const NODE_STRING: u8 = 4;
+const NODE_ARRAY: u8 = 5;
+const NODE_OBJECT: u8 = 6;
+
+/// The same limit as the recursion limit of `serde_json`.
+const MAX_TREE_DEPTH: usize = 128;
+
+pub(crate) type BinaryLocale<'a, 'b> = (String, JSONGetTextMap<'b, 'a>);
+
+pub(crate) struct BinaryCatalog<'a, 'b> {
+    pub(crate) default_key:  String,
+    pub(crate) aliases:      Vec<(String, String)>,
+    pub(crate) sources:      Vec<(String, &'b str)>,
+    pub(crate) descriptions: Vec<(&'b str, &'b str)>,
+    pub(crate) locales:      Vec<BinaryLocale<'a, 'b>>,
+}
+
+pub(crate) struct DecodedBinaryCatalog<'a> {
+    pub(crate) default_key:  &'a str,
+    pub(crate) aliases:      Vec<(&'a str, &'a str)>,
+    pub(crate) sources:      Vec<(&'a str, &'a str)>,
+    pub(crate) descriptions: Vec<(&'a str, &'a str)>,
+    pub(crate) locales:      Vec<(&'a str, HashMap<&'a str, JSONGetTextValue<'a>>)>,
+}
+
+/// Write a binary catalog.
+///
+/// Layout (little-endian):
+///
+/// * header: magic (4 bytes), version (u16), reserved (u16), FNV-1a 64 checksum of the payload (u64), payload length (u64)
+/// * payload: default key, the aliases of keys, the source paths of keys, the descriptions of texts, number of locales (u32), and for each locale its key, number of its own texts (u32), and for each text its id, a tag (u8, `0` for strings and `1` for other JSON values) and its data
+///
+/// Aliases, source paths and descriptions are each stored as their number (u32) followed by string pairs. A JSON value is stored as a node tag (u8) followed by its data, so its strings can be borrowed when it is read. Every string is stored as its length (u32) followed by its UTF-8 bytes. Keys and text ids are sorted so that the output is deterministic.
+pub(crate) fn write_binary_catalog<W: Write>(
+    mut writer: W,
+    mut catalog: BinaryCatalog,
+) -> Result<(), io::Error> {
+    catalog.aliases.sort_unstable();
+    catalog.sources.sort_unstable();
+    catalog.descriptions.sort_unstable();
+    catalog.locales.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut payload = Vec::new();
+
+    write_str(&mut payload, &catalog.default_key)?;
+
+    write_pairs(&mut payload, catalog.aliases.iter().map(|(a, b)| (a.as_str(), b.as_str())))?;
+    write_pairs(&mut payload, catalog.sources.iter().map(|(a, b)| (a.as_str(), *b)))?;
+    write_pairs(&mut payload, catalog.descriptions.iter().copied())?;
+
+    write_u32(&mut payload, catalog.locales.len())?;
+
+    for (key, map) in catalog.locales {
+        let mut texts: Vec<(&str, &JSONGetTextValue)> = map.translated().collect();
+
+        texts.sort_unstable_by_key(|(text, _)| *text);
+
+        write_str(&mut payload, &key)?;
+        write_u32(&mut payload, texts.len())?;
+
+        for (text, value) in texts {
+            write_str(&mut payload, text)?;
+
+            match value.as_str() {
+                Some(s) => {
+                    payload.push(TAG_STRING);
+                    write_str(&mut payload, s)?;
+                },
+                None => {
+                    payload.push(TAG_TREE);
+                    write_tree(&mut payload, &value.to_json_value().unwrap())?;
+                },
+            }
+        }
+    }
+
+    writer.write_all(&BINARY_CATALOG_MAGIC)?;
+    writer.write_all(&BINARY_CATALOG_VERSION.to_le_bytes())?;
+    writer.write_all(&[0, 0])?;
+    writer.write_all(&fnv1a64(&payload).to_le_bytes())?;
+    writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+    writer.write_all(&payload)?;
+
+    Ok(())
+}
+
+/// Read a binary catalog. Text ids and strings, including the ones in objects and arrays, are borrowed from `bytes` without being copied.
+pub(crate) fn read_binary_catalog(
+    bytes: &[u8],
+) -> Result<DecodedBinaryCatalog<'_>, JSONGetTextFormatError> {
+    if bytes.len() < HEADER_LENGTH || bytes[..4] != BINARY_CATALOG_MAGIC {
+        return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+            "The data is not a binary catalog.",
+        )));
+    }
+
+    let version = u16::from_le_bytes(bytes[4..6].try_into().unwrap());
+
+    if version != BINARY_CATALOG_VERSION {
+        return Err(JSONGetTextFormatError::UnsupportedVersion(version));
+    }
+
+    let checksum = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+    let payload_length = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+
+    let payload = &bytes[HEADER_LENGTH..];
+
+    if payload.len() as u64 != payload_length {
+        return Err(truncated());
+    }
+
+    if fnv1a64(payload) != checksum {
+        return Err(JSONGetTextFormatError::ChecksumMismatch);
+    }
+
+    let mut reader = PayloadReader {
+        payload,
+        offset: 0,
+    };
+
+    let default_key = reader.read_str()?;
+
+    let aliases = reader.read_pairs()?;
+    let sources = reader.read_pairs()?;
+    let descriptions = reader.read_pairs()?;
+
+    let locale_count = reader.read_u32()?;
+
+    let mut locales = Vec::with_capacity(locale_count.min(payload.len()));
+
+    for _ in 0..locale_count {
+        let key = reader.read_str()?;
+
+        let text_count = reader.read_u32()?;
+
+        let mut map = HashMap::with_capacity(text_count.min(payload.len()));
+
+        for _ in 0..text_count {
+            let text = reader.read_str()?;
+
+            let value = match reader.read_u8()? {
+                TAG_STRING => JSONGetTextValue::from_str(reader.read_str()?),
+                TAG_TREE => JSONGetTextValue::Tree(reader.read_tree(0)?),
+                tag => return Err(invalid_tag(tag)),
+            };
+
+            map.insert(text, value);
+        }
+
+        locales.push((key, map));
+    }
+
+    if reader.offset != payload.len() {
+        return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+            "There is trailing data after the catalog.",
+        )));
+    }
+
+    Ok(DecodedBinaryCatalog {
+        default_key,
+        aliases,
+        sources,
+        descriptions,
+        locales,
+    })
+}
+
+struct PayloadReader<'a> {
+    payload: &'a [u8],
+    offset:  usize,
+}
+
+impl<'a> PayloadReader<'a> {
+    #[inline]
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], JSONGetTextFormatError> {
+        let end = self.offset.checked_add(length).ok_or_else(truncated)?;
+
+        let bytes = self.payload.get(self.offset..end).ok_or_else(truncated)?;
+
+        self.offset = end;
+
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> Result<u8, JSONGetTextFormatError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    #[inline]
+    fn read_u32(&mut self) -> Result<usize, JSONGetTextFormatError> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()) as usize)
+    }
+
+    #[inline]
+    fn read_str(&mut self) -> Result<&'a str, JSONGetTextFormatError> {
+        let length = self.read_u32()?;
+
+        str::from_utf8(self.read_bytes(length)?)
+            .map_err(|err| JSONGetTextFormatError::InvalidFormat(err.to_string()))
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.payload.len() - self.offset
+    }
+
+    fn read_pairs(&mut self) -> Result<Vec<(&'a str, &'a str)>, JSONGetTextFormatError> {
+        let count = self.read_u32()?;
+
+        let mut pairs = Vec::with_capacity(count.min(self.remaining()));
+
+        for _ in 0..count {
+            pairs.push((self.read_str()?, self.read_str()?));
+        }
+
+        Ok(pairs)
+    }
+
+    fn read_tree(&mut self, depth: usize) -> Result<JSONTree<'a>, JSONGetTextFormatError> {
+        if depth > MAX_TREE_DEPTH {
+            return Err(JSONGetTextFormatError::InvalidFormat(String::from(
+                "A JSON value is nested too deeply.",
+            )));
+        }
+
+        let tree = match self.read_u8()? {
+            NODE_NULL => JSONTree::Null,
+            NODE_FALSE => JSONTree::Bool(false),
+            NODE_TRUE => JSONTree::Bool(true),
+            NODE_NUMBER => {
+                let number = self.read_str()?;
+
+                JSONTree::Number(number.parse::<Number>().map_err(|_| {
+                    JSONGetTextFormatError::InvalidFormat(format!(
+                        "`{}` is not a valid number.",
+                        number
+                    ))
+                })?)
+            },
+            NODE_STRING => JSONTree::String(JSONTreeStr::from(self.read_str()?)),
+            NODE_ARRAY => {
+                let count = self.read_u32()?;
+
+                let mut array = Vec::with_capacity(count.min(self.remaining()));
+
+                for _ in 0..count {
+                    array.push(self.read_tree(depth + 1)?);
+                }
+
+                JSONTree::Array(array)
+            },
+            NODE_OBJECT => {
+                let count = self.read_u32()?;
+
+                let mut object = Vec::with_capacity(count.min(self.remaining()));
+
+                for _ in 0..count {
+                    let key = JSONTreeStr::from(self.read_str()?);
+
+                    object.push((key, self.read_tree(depth + 1)?));
+                }
+
+                JSONTree::from_entries(object)
+            },
+            tag => return Err(invalid_tag(tag)),
+        };
+
+        Ok(tree)
+    }
+}
+
+#[inline]
+fn truncated() -> JSONGetTextFormatError {
+    JSONGetTextFormatError::InvalidFormat(String::from("The binary catalog is truncated."))
+}
+
+#[inline]
+fn invalid_tag(tag: u8) -> JSONGetTextFormatError {
+    JSONGetTextFormatError::InvalidFormat(format!("`{}` is not a valid tag.", tag))
+}
+
+#[inline]
+fn write_u32(payload: &mut Vec<u8>, n: usize) -> Result<(), io::Error> {
+    let n: u32 = n
+        .try_into()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "The catalog is too large."))?;
+
+    payload.extend_from_slice(&n.to_le_bytes());
+
+    Ok(())
+}
+
+#[inline]
+fn write_str(payload: &mut Vec<u8>, s: &str) -> Result<(), io::Error> {
+    write_u32(payload, s.len())?;
+
+    payload.extend_from_slice(s.as_bytes());
+
+    Ok(())
+}
+
+fn write_pairs<'s, I: ExactSizeIterator<Item = (&'s str, &'s str)>>(
+    payload: &mut Vec<u8>,
+    pairs: I,
+) -> Result<(), io::Error> {
+    write_u32(payload, pairs.len())?;
+
+    for (a, b) in pairs {
+        write_str(payload, a)?;
+        write_str(payload, b)?;
+    }
+
+    Ok(())
+}
+
+fn write_tree(payload: &mut Vec<u8>, value: &Value) -> Result<(), io::Error> {
+    match value {
+        Value::Null => payload.push(NODE_NULL),
+        Value::Bool(false) => payload.push(NODE_FALSE),
+        Value::Bool(true) => payload.push(NODE_TRUE),
+        Value::Number(n) => {
+            payload.push(NODE_NUMBER);
+            write_str(payload, &n.to_string())?;
+        },
+        Value::String(s) => {
+            payload.push(NODE_STRING);
+            write_str(payload, s)?;
+        },
+        Value::Array(array) => {
+            payload.push(NODE_ARRAY);
+            write_u32(payload, array.len())?;
+
+            for value in array {
+                write_tree(payload, value)?;
+            }
+        },
+        Value::Object(object) => {
+            payload.push(NODE_OBJECT);
+            write_u32(payload, object.len())?;
+
+            for (key, value) in object {
+                write_str(payload, key)?;
+                write_tree(payload, value)?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+pub(crate) fn fnv1a64(data: &[u8]) -> u64 {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+
+    for &b in data {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+
+    hash
+}
@@ -0,0 +1,73 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(any(feature = "android", feature = "apple"))]
+use quick_xml::Error as XMLError;
+
+/// Errors of the converters. Some variants only exist when their features are enabled, so the enum is non-exhaustive.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum JSONGetTextFormatError {
+    /// The source document is not a valid document of the expected format.
+    InvalidFormat(String),
+    /// The value of the text cannot be represented in the target format.
+    UnsupportedValue { text: String },
+    /// The version of the binary catalog is not supported.
+    UnsupportedVersion(u16),
+    /// The checksum of the binary catalog does not match its content.
+    ChecksumMismatch,
+    #[cfg(any(feature = "android", feature = "apple"))]
+    XMLError(XMLError),
+}
+
+impl Display for JSONGetTextFormatError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JSONGetTextFormatError::InvalidFormat(message) => f.write_str(message),
+            JSONGetTextFormatError::UnsupportedValue {
+                text,
+            } => f.write_fmt(format_args!(
+                "The value of the text `{}` cannot be represented in the target format.",
+                text
+            )),
+            JSONGetTextFormatError::UnsupportedVersion(version) => f.write_fmt(format_args!(
+                "The version `{}` of the binary catalog is not supported.",
+                version
+            )),
+            JSONGetTextFormatError::ChecksumMismatch => {
+                f.write_str("The checksum of the binary catalog does not match.")
+            },
+            #[cfg(any(feature = "android", feature = "apple"))]
+            JSONGetTextFormatError::XMLError(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for JSONGetTextFormatError {}
+
+#[cfg(any(feature = "android", feature = "apple"))]
+impl From<XMLError> for JSONGetTextFormatError {
+    #[inline]
+    fn from(v: XMLError) -> JSONGetTextFormatError {
+        JSONGetTextFormatError::XMLError(v)
+    }
+}
+
+#[cfg(any(feature = "android", feature = "apple"))]
+impl From<quick_xml::escape::EscapeError> for JSONGetTextFormatError {
+    #[inline]
+    fn from(v: quick_xml::escape::EscapeError) -> JSONGetTextFormatError {
+        JSONGetTextFormatError::XMLError(v.into())
+    }
+}
+
+#[cfg(any(feature = "android", feature = "apple"))]
+impl From<quick_xml::events::attributes::AttrError> for JSONGetTextFormatError {
+    #[inline]
+    fn from(v: quick_xml::events::attributes::AttrError) -> JSONGetTextFormatError {
+        JSONGetTextFormatError::XMLError(v.into())
+    }
+}
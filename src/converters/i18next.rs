@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use super::{JSONGetTextFormatError, PLURAL_CATEGORIES};
+use crate::{
+    serde_json::{self, Map, Value},
+    JSONGetTextValue,
+};
+
+const KEY_SEPARATOR: char = '.';
+const PLURAL_SUFFIX_V3: &str = "_plural";
+const NESTING_PREFIX: &str = "$t(";
+const MAX_NESTING_DEPTH: usize = 8;
+
+/// Parse an i18next JSON document into a text map.
+///
+/// * Nested objects are flattened, and their keys are joined with `.`, e.g. `{"nav": {"home": "Home"}}` becomes `nav.home`.
+/// * Plural suffixes are grouped into a plural object whose keys are plural categories. Both `key_one` / `key_other` (JSON v4) and `key` / `key_plural` (JSON v3) are understood. A `key_other` text which has no other plural category, e.g. `key_one` or `key_zero`, is kept as it is.
+/// * `$t(key)` nestings which have no options are replaced with the string they refer to.
+pub fn parse_i18next<S: AsRef<str>>(
+    json: S,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextFormatError> {
+    let value: Map<String, Value> = serde_json::from_str(json.as_ref())
+        .map_err(|err| JSONGetTextFormatError::InvalidFormat(err.to_string()))?;
+
+    let mut flattened = HashMap::new();
+
+    flatten(&mut flattened, None, value);
+
+    group_plurals(&mut flattened);
+
+    let resolved: Vec<(String, Value)> = flattened
+        .iter()
+        .filter_map(|(k, v)| {
+            resolve_nesting(&flattened, v, 0).map(|resolved| (k.clone(), resolved))
+        })
+        .collect();
+
+    flattened.extend(resolved);
+
+    Ok(flattened.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v))).collect())
+}
+
+fn flatten(map: &mut HashMap<String, Value>, prefix: Option<&str>, object: Map<String, Value>) {
+    for (k, v) in object {
+        let key = match prefix {
+            Some(prefix) => format!("{}{}{}", prefix, KEY_SEPARATOR, k),
+            None => k,
+        };
+
+        match v {
+            Value::Object(object) => flatten(map, Some(&key), object),
+            _ => {
+                map.insert(key, v);
+            },
+        }
+    }
+}
+
+fn group_plurals(map: &mut HashMap<String, Value>) {
+    let v4_bases: Vec<String> = map
+        .keys()
+        .filter_map(|k| k.strip_suffix("_other"))
+        .filter(|base| !map.contains_key(*base))
+        .filter(|base| {
+            PLURAL_CATEGORIES.iter().any(|category| {
+                *category != "other" && map.contains_key(&format!("{}_{}", base, category))
+            })
+        })
+        .map(String::from)
+        .collect();
+
+    for base in v4_bases {
+        let mut plural = Map::new();
+
+        for category in PLURAL_CATEGORIES {
+            if let Some(v) = map.remove(&format!("{}_{}", base, category)) {
+                plural.insert(String::from(category), v);
+            }
+        }
+
+        map.insert(base, Value::Object(plural));
+    }
+
+    let v3_bases: Vec<String> = map
+        .keys()
+        .filter_map(|k| k.strip_suffix(PLURAL_SUFFIX_V3))
+        .filter(|base| map.get(*base).map(Value::is_string).unwrap_or(false))
+        .map(String::from)
+        .collect();
+
+    for base in v3_bases {
+        let other = map.remove(&format!("{}{}", base, PLURAL_SUFFIX_V3)).unwrap();
+        let one = map.remove(&base).unwrap();
+
+        let mut plural = Map::new();
+
+        plural.insert(String::from("one"), one);
+        plural.insert(String::from("other"), other);
+
+        map.insert(base, Value::Object(plural));
+    }
+}
+
+/// Returns `None` if nothing needs to be replaced.
+fn resolve_nesting(map: &HashMap<String, Value>, value: &Value, depth: usize) -> Option<Value> {
+    match value {
+        Value::String(s) => resolve_nesting_str(map, s, depth).map(Value::String),
+        Value::Object(object) => {
+            let mut changed = false;
+
+            let object = object
+                .iter()
+                .map(|(k, v)| {
+                    let v = match resolve_nesting(map, v, depth) {
+                        Some(v) => {
+                            changed = true;
+
+                            v
+                        },
+                        None => v.clone(),
+                    };
+
+                    (k.clone(), v)
+                })
+                .collect();
+
+            if changed {
+                Some(Value::Object(object))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+fn resolve_nesting_str(map: &HashMap<String, Value>, s: &str, depth: usize) -> Option<String> {
+    if depth >= MAX_NESTING_DEPTH || !s.contains(NESTING_PREFIX) {
+        return None;
+    }
+
+    let mut result = String::with_capacity(s.len());
+
+    let mut changed = false;
+
+    let mut remaining = s;
+
+    while let Some(index) = remaining.find(NESTING_PREFIX) {
+        let (before, after) = remaining.split_at(index);
+
+        result.push_str(before);
+
+        let inner = &after[NESTING_PREFIX.len()..];
+
+        let referred = inner.find(')').and_then(|end| {
+            let key = &inner[..end];
+
+            // nestings with options need the runtime of i18next
+            if key.contains(',') {
+                return None;
+            }
+
+            let referred = match map.get(key.trim())? {
+                Value::String(s) => s.as_str(),
+                Value::Object(plural) => plural.get("other")?.as_str()?,
+                _ => return None,
+            };
+
+            let referred = resolve_nesting_str(map, referred, depth + 1)
+                .unwrap_or_else(|| String::from(referred));
+
+            Some((referred, end))
+        });
+
+        match referred {
+            Some((referred, end)) => {
+                result.push_str(&referred);
+
+                remaining = &inner[end + 1..];
+
+                changed = true;
+            },
+            None => {
+                result.push_str(NESTING_PREFIX);
+
+                remaining = inner;
+            },
+        }
+    }
+
+    result.push_str(remaining);
+
+    if changed {
+        Some(result)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(feature = "android")]
+mod android;
+#[cfg(feature = "apple")]
+mod apple;
+mod arb;
+mod binary;
+mod errors;
+mod i18next;
+
+#[cfg(feature = "android")]
+pub use android::*;
+#[cfg(feature = "apple")]
+pub use apple::*;
+pub use arb::*;
+pub use binary::*;
+pub use errors::*;
+pub use i18next::*;
+
+/// The CLDR plural categories which can be used as keys of a plural object.
+pub const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// The `.stringsdict` keys which a plural object can keep besides its plural categories, so that the format key and the value type of the plural variable survive a round trip.
+#[cfg(any(feature = "android", feature = "apple"))]
+const PLURAL_METADATA_KEYS: [&str; 2] =
+    ["NSStringLocalizedFormatKey", "NSStringFormatValueTypeKey"];
+
+#[cfg(any(feature = "android", feature = "apple"))]
+#[inline]
+pub(crate) fn is_plural_object(
+    map: &crate::serde_json::Map<String, crate::serde_json::Value>,
+) -> bool {
+    map.keys().any(|k| PLURAL_CATEGORIES.contains(&k.as_str()))
+        && map.iter().all(|(k, v)| {
+            (PLURAL_CATEGORIES.contains(&k.as_str()) || PLURAL_METADATA_KEYS.contains(&k.as_str()))
+                && v.is_string()
+        })
+}
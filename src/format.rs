@@ -0,0 +1,76 @@
+use std::fmt::{Display, Write};
+
+use crate::JSONGetTextValue;
+
+/// Replace the `{name}` placeholders in a text with the arguments which have the same names. `{{` and `}}` are written as `{` and `}`. Placeholders which have no arguments are kept as they are.
+///
+/// ```rust
+/// let s =
+///     json_gettext::format_text("Hello, {name}! {{ok}}", &[("name", &"Len")]);
+///
+/// assert_eq!("Hello, Len! {ok}", s);
+/// ```
+pub fn format_text<S: AsRef<str>>(text: S, args: &[(&str, &dyn Display)]) -> String {
+    format_text_with(text.as_ref(), args, |s, arg| write!(s, "{}", arg).unwrap())
+}
+
+/// Replace the placeholders like [`format_text`] does, but write the arguments with `write_arg`.
+pub(crate) fn format_text_with<F: FnMut(&mut String, &dyn Display)>(
+    text: &str,
+    args: &[(&str, &dyn Display)],
+    mut write_arg: F,
+) -> String {
+    let mut s = String::with_capacity(text.len());
+
+    let mut remaining = text;
+
+    while let Some(index) = remaining.find(['{', '}']) {
+        let (before, after) = remaining.split_at(index);
+
+        s.push_str(before);
+
+        if after.starts_with("{{") || after.starts_with("}}") {
+            s.push_str(&after[..1]);
+
+            remaining = &after[2..];
+
+            continue;
+        }
+
+        if after.starts_with('{') {
+            if let Some(end) = after.find('}') {
+                let name = &after[1..end];
+
+                if let Some((_, arg)) = args.iter().find(|(n, _)| *n == name) {
+                    write_arg(&mut s, *arg);
+
+                    remaining = &after[end + 1..];
+
+                    continue;
+                }
+            }
+        }
+
+        s.push_str(&after[..1]);
+
+        remaining = &after[1..];
+    }
+
+    s.push_str(remaining);
+
+    s
+}
+
+/// Used by the functions generated by the `messages!` macro. Format the string value of a text, or the text of the default JSON file if the value is not a string.
+#[doc(hidden)]
+#[inline]
+pub fn format_message(
+    value: Option<JSONGetTextValue>,
+    default_text: &str,
+    args: &[(&str, &dyn Display)],
+) -> String {
+    match value.as_ref().and_then(|value| value.as_str()) {
+        Some(text) => format_text(text, args),
+        None => format_text(default_text, args),
+    }
+}
@@ -0,0 +1,68 @@
+use std::{io, ops::Deref, sync::Arc};
+
+use crate::{IntoLocaleKey, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, LocaleKey};
+
+/// A handle of a `JSONGetText` instance which is built from json files. In release builds (or with the `embed` feature), the files are embedded into the executable binary file.
+#[derive(Debug)]
+pub struct JSONGetTextHandle<K: LocaleKey = String> {
+    json_gettext: Arc<JSONGetText<'static, K>>,
+}
+
+impl<K: LocaleKey> JSONGetTextHandle<K> {
+    /// Build a `JSONGetText` instance from embedded json strings.
+    pub fn from_jsons<D: IntoLocaleKey<Key = K>, Q: IntoLocaleKey<Key = K>>(
+        default_key: D,
+        source: Vec<(Q, &'static str)>,
+    ) -> Result<JSONGetTextHandle<K>, JSONGetTextBuildError> {
+        let mut builder = JSONGetTextBuilder::new(default_key);
+
+        for (key, json) in source {
+            builder.add_json(key, json)?;
+        }
+
+        Ok(JSONGetTextHandle {
+            json_gettext: Arc::new(builder.build()?)
+        })
+    }
+
+    /// Returns `true` if the json files are embedded into the executable binary file. They are in this build.
+    #[inline]
+    pub fn is_embedded(&self) -> bool {
+        true
+    }
+
+    /// The embedded json files cannot be modified, so this method always returns `false`.
+    #[inline]
+    pub fn is_modified(&self) -> Result<bool, io::Error> {
+        Ok(false)
+    }
+
+    /// The embedded json files cannot be modified, so this method does nothing and returns `false`.
+    #[inline]
+    pub fn reload_if_needed(&mut self) -> Result<bool, JSONGetTextBuildError> {
+        Ok(false)
+    }
+
+    /// The embedded json files cannot be modified, so this method does nothing.
+    #[inline]
+    pub fn reload(&mut self) -> Result<(), JSONGetTextBuildError> {
+        Ok(())
+    }
+}
+
+impl<K: LocaleKey> JSONGetTextHandle<K> {
+    /// Get the current `JSONGetText` instance, which can be shared across threads. Reloading replaces the instance of the handle, and the shared ones are not affected.
+    #[inline]
+    pub fn share(&self) -> Arc<JSONGetText<'static, K>> {
+        self.json_gettext.clone()
+    }
+}
+
+impl<K: LocaleKey> Deref for JSONGetTextHandle<K> {
+    type Target = JSONGetText<'static, K>;
+
+    #[inline]
+    fn deref(&self) -> &JSONGetText<'static, K> {
+        &self.json_gettext
+    }
+}
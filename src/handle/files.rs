@@ -0,0 +1,104 @@
+use std::{io, ops::Deref, path::PathBuf, sync::Arc, time::SystemTime};
+
+use crate::{IntoLocaleKey, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, LocaleKey};
+
+/// A handle of a `JSONGetText` instance which is built from json files. In debug builds (without the `embed` feature), the files are read from the disk and can be reloaded when they are modified.
+#[derive(Debug)]
+pub struct JSONGetTextHandle<K: LocaleKey = String> {
+    json_gettext: Arc<JSONGetText<'static, K>>,
+    files:        Vec<(K, PathBuf, Option<SystemTime>)>,
+}
+
+impl<K: LocaleKey> JSONGetTextHandle<K> {
+    /// Build a `JSONGetText` instance from json files.
+    pub fn from_files<D: IntoLocaleKey<Key = K>, Q: IntoLocaleKey<Key = K>, P: Into<PathBuf>>(
+        default_key: D,
+        source: Vec<(Q, P)>,
+    ) -> Result<JSONGetTextHandle<K>, JSONGetTextBuildError> {
+        let mut files = source
+            .into_iter()
+            .map(|(key, path)| (key.into_locale_key(), path.into(), None))
+            .collect::<Vec<(K, PathBuf, Option<SystemTime>)>>();
+
+        let json_gettext = Arc::new(load(default_key.into_locale_key(), &mut files)?);
+
+        Ok(JSONGetTextHandle {
+            json_gettext,
+            files,
+        })
+    }
+
+    /// Returns `true` if the json files are embedded into the executable binary file. They are not in this build.
+    #[inline]
+    pub fn is_embedded(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if any of the json files has been modified since they were loaded (or since the last reloading failed).
+    pub fn is_modified(&self) -> Result<bool, io::Error> {
+        for (_, path, mtime) in self.files.iter() {
+            let new_mtime = path.metadata()?.modified().ok();
+
+            match (mtime, new_mtime) {
+                (Some(mtime), Some(new_mtime)) if new_mtime <= *mtime => (),
+                _ => return Ok(true),
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Reload the json files if any of them has been modified. Returns `true` if they are reloaded. If the reloading fails, the previous context is kept, and the files are not reloaded again until they are modified again.
+    pub fn reload_if_needed(&mut self) -> Result<bool, JSONGetTextBuildError> {
+        let do_reload = self.is_modified()?;
+
+        if do_reload {
+            self.reload()?;
+        }
+
+        Ok(do_reload)
+    }
+
+    /// Reload the json files. If the reloading fails, the previous context is kept.
+    pub fn reload(&mut self) -> Result<(), JSONGetTextBuildError> {
+        self.json_gettext =
+            Arc::new(load(self.json_gettext.get_default_key().clone(), &mut self.files)?);
+
+        Ok(())
+    }
+}
+
+/// Build a `JSONGetText` instance from json files. The modification times are updated even if it fails, so that broken files are not read again until they are modified.
+fn load<K: LocaleKey>(
+    default_key: K,
+    files: &mut [(K, PathBuf, Option<SystemTime>)],
+) -> Result<JSONGetText<'static, K>, JSONGetTextBuildError> {
+    for (_, path, mtime) in files.iter_mut() {
+        *mtime = path.metadata()?.modified().ok();
+    }
+
+    let mut builder = JSONGetTextBuilder::new(default_key);
+
+    for (key, path, _) in files.iter() {
+        builder.add_json_file(key.clone(), path)?;
+    }
+
+    builder.build()
+}
+
+impl<K: LocaleKey> JSONGetTextHandle<K> {
+    /// Get the current `JSONGetText` instance, which can be shared across threads. Reloading replaces the instance of the handle, and the shared ones are not affected.
+    #[inline]
+    pub fn share(&self) -> Arc<JSONGetText<'static, K>> {
+        self.json_gettext.clone()
+    }
+}
+
+impl<K: LocaleKey> Deref for JSONGetTextHandle<K> {
+    type Target = JSONGetText<'static, K>;
+
+    #[inline]
+    fn deref(&self) -> &JSONGetText<'static, K> {
+        &self.json_gettext
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(all(debug_assertions, not(feature = "embed")))]
+mod files;
+
+#[cfg(any(not(debug_assertions), feature = "embed"))]
+mod embedded;
+
+#[cfg(any(not(debug_assertions), feature = "embed"))]
+pub use embedded::*;
+#[cfg(all(debug_assertions, not(feature = "embed")))]
+pub use files::*;
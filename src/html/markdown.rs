@@ -0,0 +1,76 @@
+use std::fmt::{Display, Write};
+
+use pulldown_cmark::{html, Parser};
+
+use super::{escape_html, HTMLText};
+use crate::format::format_text_with;
+
+/// Replace the `{name}` placeholders in a Markdown text like [`format_text`](crate::format_text) does, and render it to HTML. The text (including inline HTML) is trusted, and the arguments are escaped so that they are always rendered as plain text.
+///
+/// ```rust
+/// let html = json_gettext::format_markdown("Hello, **{name}**!", &[(
+///     "name", &"*<Len>*",
+/// )]);
+///
+/// assert_eq!("<p>Hello, <strong>*&lt;Len&gt;*</strong>!</p>\n", html);
+/// ```
+pub fn format_markdown<S: AsRef<str>>(text: S, args: &[(&str, &dyn Display)]) -> HTMLText {
+    let text = text.as_ref();
+
+    // the arguments are replaced with alphanumeric tokens, which Markdown renders unchanged in any context (e.g. in a code span), and are substituted into the rendered HTML
+    let mut marker = String::from("jsongettextarg");
+
+    while text.contains(marker.as_str()) {
+        marker.push('x');
+    }
+
+    let mut arg_values = Vec::new();
+
+    let markdown = format_text_with(text, args, |s, arg| {
+        write!(s, "{marker}{}{marker}", arg_values.len()).unwrap();
+
+        arg_values.push(arg.to_string());
+    });
+
+    let mut rendered = String::with_capacity(markdown.len() + markdown.len() / 2);
+
+    html::push_html(&mut rendered, Parser::new(&markdown));
+
+    if arg_values.is_empty() {
+        return HTMLText::from_trusted(rendered);
+    }
+
+    let mut s = String::with_capacity(rendered.len());
+
+    let mut remaining = rendered.as_str();
+
+    while let Some(index) = remaining.find(marker.as_str()) {
+        s.push_str(&remaining[..index]);
+
+        let after = &remaining[index + marker.len()..];
+
+        let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+
+        let arg = after[digits..]
+            .strip_prefix(marker.as_str())
+            .and_then(|rest| Some((arg_values.get(after[..digits].parse::<usize>().ok()?)?, rest)));
+
+        match arg {
+            Some((arg, rest)) => {
+                s.push_str(&escape_html(arg));
+
+                remaining = rest;
+            },
+            None => {
+                // e.g. the marker was written with character references in the text
+                s.push_str(&marker);
+
+                remaining = after;
+            },
+        }
+    }
+
+    s.push_str(remaining);
+
+    HTMLText::from_trusted(s)
+}
@@ -0,0 +1,203 @@
+#[cfg(feature = "markdown")]
+mod markdown;
+
+#[cfg(feature = "rocket")]
+use std::io::Cursor;
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "actix")]
+use actix_web::{
+    body::BoxBody, http::header::ContentType as ActixContentType, HttpRequest, HttpResponse,
+    Responder as ActixResponder,
+};
+#[cfg(feature = "axum")]
+use axum::response::{Html, IntoResponse, Response as AxumResponse};
+#[cfg(feature = "markdown")]
+pub use markdown::*;
+#[cfg(feature = "rocket")]
+use rocket::{
+    http::ContentType,
+    request::Request,
+    response::{self, Responder, Response},
+};
+
+use crate::format::format_text_with;
+
+/// An HTML fragment. Its markup comes from a trusted source (e.g. the catalog), and its untrusted parts (e.g. the arguments) have been escaped, so it can be written into a page as it is.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HTMLText(String);
+
+impl HTMLText {
+    /// Wrap trusted markup. It is **not** escaped.
+    #[inline]
+    pub fn from_trusted<S: Into<String>>(html: S) -> HTMLText {
+        HTMLText(html.into())
+    }
+
+    /// Escape a plain text.
+    #[inline]
+    pub fn from_untrusted<S: AsRef<str>>(text: S) -> HTMLText {
+        HTMLText(escape_html(text.as_ref()).into_owned())
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Display for HTMLText {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for HTMLText {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<HTMLText> for String {
+    #[inline]
+    fn from(v: HTMLText) -> String {
+        v.0
+    }
+}
+
+impl PartialEq<HTMLText> for str {
+    #[inline]
+    fn eq(&self, other: &HTMLText) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<HTMLText> for &str {
+    #[inline]
+    fn eq(&self, other: &HTMLText) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<str> for HTMLText {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+/// Escape `&`, `<`, `>`, `"` and `'` so that a text can be put into HTML content or a quoted attribute.
+///
+/// ```rust
+/// assert_eq!(
+///     "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;",
+///     json_gettext::escape_html("<b>Tom & Jerry</b>")
+/// );
+/// ```
+pub fn escape_html<S: AsRef<str> + ?Sized>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut s = String::with_capacity(text.len() + 8);
+
+    push_escaped_html(&mut s, text);
+
+    Cow::Owned(s)
+}
+
+fn push_escaped_html(s: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => s.push_str("&amp;"),
+            '<' => s.push_str("&lt;"),
+            '>' => s.push_str("&gt;"),
+            '"' => s.push_str("&quot;"),
+            '\'' => s.push_str("&#x27;"),
+            _ => s.push(c),
+        }
+    }
+}
+
+/// Replace the `{name}` placeholders in a text like [`format_text`](crate::format_text) does. The text is treated as trusted markup, and only the arguments are escaped.
+///
+/// ```rust
+/// let html = json_gettext::format_html("Hello, <b>{name}</b>!", &[(
+///     "name",
+///     &"<i>Len</i>",
+/// )]);
+///
+/// assert_eq!("Hello, <b>&lt;i&gt;Len&lt;/i&gt;</b>!", html);
+/// ```
+pub fn format_html<S: AsRef<str>>(text: S, args: &[(&str, &dyn Display)]) -> HTMLText {
+    HTMLText(format_text_with(text.as_ref(), args, |s, arg| push_escaped_html(s, &arg.to_string())))
+}
+
+/// Returns `true` if a text ID is flagged as Markdown by ending with `.md` or `_md`, e.g. `intro.md`.
+#[inline]
+pub fn is_markdown_text_id<S: AsRef<str> + ?Sized>(text: &S) -> bool {
+    let text = text.as_ref();
+
+    text.ends_with(".md") || text.ends_with("_md")
+}
+
+/// Render a text as HTML. A text whose ID is flagged as Markdown is rendered from Markdown if the `markdown` feature is enabled.
+#[inline]
+pub(crate) fn render_text_html(
+    text_id: &str,
+    text: &str,
+    args: &[(&str, &dyn Display)],
+) -> HTMLText {
+    #[cfg(feature = "markdown")]
+    if is_markdown_text_id(text_id) {
+        return format_markdown(text, args);
+    }
+
+    #[cfg(not(feature = "markdown"))]
+    let _ = text_id;
+
+    format_html(text, args)
+}
+
+#[cfg(feature = "rocket")]
+impl<'r, 'o: 'r> Responder<'r, 'o> for HTMLText {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = Response::build();
+
+        let s = self.into_string();
+
+        response.header(ContentType::HTML).sized_body(s.len(), Cursor::new(s));
+
+        response.ok()
+    }
+}
+
+#[cfg(feature = "actix")]
+impl ActixResponder for HTMLText {
+    type Body = BoxBody;
+
+    #[inline]
+    fn respond_to(self, _: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok().content_type(ActixContentType::html()).body(self.into_string())
+    }
+}
+
+#[cfg(feature = "axum")]
+impl IntoResponse for HTMLText {
+    #[inline]
+    fn into_response(self) -> AxumResponse {
+        Html(self.into_string()).into_response()
+    }
+}
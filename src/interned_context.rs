@@ -0,0 +1,129 @@
+use std::{collections::HashMap, hash::Hash, sync::Arc};
+
+use crate::JSONGetTextValue;
+
+/// Text ids shared by all keys. Every text id is allocated once and looked up by its index.
+#[derive(Debug)]
+pub(crate) struct TextIds {
+    texts: Vec<Arc<str>>,
+    index: HashMap<Arc<str>, usize>,
+}
+
+impl TextIds {
+    #[inline]
+    pub(crate) fn get_index(&self, text: &str) -> Option<usize> {
+        self.index.get(text).copied()
+    }
+
+    #[inline]
+    pub(crate) fn get_text(&self, index: usize) -> &str {
+        &self.texts[index]
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.texts.len()
+    }
+}
+
+/// The values of a key in context.
+#[derive(Debug)]
+pub(crate) enum LocaleValues<'a> {
+    /// The values of the default key are stored in `InternedContext::default_values`.
+    Default,
+    /// The values which a key has on its own, sorted by the indices of their texts. Texts which are not translated for the key take no space.
+    Own(Vec<(u32, JSONGetTextValue<'a>)>),
+}
+
+impl<'a> LocaleValues<'a> {
+    #[inline]
+    pub(crate) fn into_owned(self) -> LocaleValues<'static> {
+        match self {
+            LocaleValues::Default => LocaleValues::Default,
+            LocaleValues::Own(values) => LocaleValues::Own(
+                values.into_iter().map(|(i, value)| (i, value.into_owned())).collect(),
+            ),
+        }
+    }
+}
+
+/// The compact storage of context. Text ids are shared by all keys, the values of the default key are stored in a vector indexed by the text ids, and other keys store only their own values.
+#[derive(Debug)]
+pub(crate) struct InternedContext<'a, K> {
+    pub(crate) ids:            TextIds,
+    pub(crate) default_values: Vec<JSONGetTextValue<'a>>,
+    pub(crate) locales:        HashMap<K, LocaleValues<'a>>,
+}
+
+impl<'a, K: Eq + Hash> InternedContext<'a, K> {
+    /// Intern the texts of the default key and index the maps of all keys by them. If a text of a key does not exist in the default key, the key and the text are returned as the error.
+    pub(crate) fn new<T: AsRef<str> + Into<Arc<str>>>(
+        default_key: K,
+        mut context: HashMap<K, HashMap<T, JSONGetTextValue<'a>>>,
+    ) -> Result<InternedContext<'a, K>, (K, String)> {
+        let default_map = context.remove(&default_key).unwrap();
+
+        let mut default_entries: Vec<(T, JSONGetTextValue<'a>)> = default_map.into_iter().collect();
+
+        default_entries.sort_unstable_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+
+        let mut texts = Vec::with_capacity(default_entries.len());
+        let mut index = HashMap::with_capacity(default_entries.len());
+        let mut default_values = Vec::with_capacity(default_entries.len());
+
+        for (i, (text, value)) in default_entries.into_iter().enumerate() {
+            let text: Arc<str> = text.into();
+
+            index.insert(text.clone(), i);
+            texts.push(text);
+            default_values.push(value);
+        }
+
+        let ids = TextIds {
+            texts,
+            index,
+        };
+
+        let mut locales = HashMap::with_capacity(context.len() + 1);
+
+        for (key, map) in context {
+            let mut values = Vec::with_capacity(map.len());
+
+            for (text, value) in map {
+                match ids.get_index(text.as_ref()) {
+                    Some(i) => values.push((i as u32, value)),
+                    None => return Err((key, String::from(text.as_ref()))),
+                }
+            }
+
+            values.sort_unstable_by_key(|(i, _)| *i);
+
+            locales.insert(key, LocaleValues::Own(values));
+        }
+
+        locales.insert(default_key, LocaleValues::Default);
+
+        Ok(InternedContext {
+            ids,
+            default_values,
+            locales,
+        })
+    }
+
+    /// Convert all borrowed values to owned ones.
+    pub(crate) fn into_owned(self) -> InternedContext<'static, K> {
+        InternedContext {
+            ids:            self.ids,
+            default_values: self
+                .default_values
+                .into_iter()
+                .map(JSONGetTextValue::into_owned)
+                .collect(),
+            locales:        self
+                .locales
+                .into_iter()
+                .map(|(key, values)| (key, values.into_owned()))
+                .collect(),
+        }
+    }
+}
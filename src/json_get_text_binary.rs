@@ -0,0 +1,94 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    fs,
+    mem::ManuallyDrop,
+    path::Path,
+    ptr::NonNull,
+};
+
+use crate::{JSONGetText, JSONGetTextBuildError, LocaleKey};
+
+/// A `JSONGetText` instance loaded from a binary catalog, which owns the bytes of the catalog and borrows text values from them instead of copying them. See [`JSONGetText::from_binary`].
+pub struct JSONGetTextBinary<K: LocaleKey = String> {
+    /// Borrows from `bytes`, so it has to be dropped before `bytes` is freed.
+    ctx:   ManuallyDrop<JSONGetText<'static, K>>,
+    /// Allocated by a `Box<[u8]>`, and never mutated or freed until the instance is dropped.
+    bytes: NonNull<[u8]>,
+}
+
+// Safety: `bytes` is only read, so sharing or sending it is as safe as sharing or sending `ctx`.
+unsafe impl<K: LocaleKey> Send for JSONGetTextBinary<K> where JSONGetText<'static, K>: Send {}
+
+unsafe impl<K: LocaleKey> Sync for JSONGetTextBinary<K> where JSONGetText<'static, K>: Sync {}
+
+impl<K: LocaleKey> JSONGetTextBinary<K> {
+    /// Load a binary catalog from bytes.
+    pub fn from_bytes<B: Into<Box<[u8]>>>(
+        bytes: B,
+    ) -> Result<JSONGetTextBinary<K>, JSONGetTextBuildError> {
+        let bytes = NonNull::from(Box::leak(bytes.into()));
+
+        // Safety: the bytes live until the instance is dropped, and `ctx` is dropped before them.
+        match JSONGetText::from_binary(unsafe { bytes.as_ref() }) {
+            Ok(ctx) => Ok(JSONGetTextBinary {
+                ctx: ManuallyDrop::new(ctx),
+                bytes,
+            }),
+            Err(err) => {
+                drop(unsafe { Box::from_raw(bytes.as_ptr()) });
+
+                Err(err)
+            },
+        }
+    }
+
+    /// Load a binary catalog file.
+    #[inline]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<JSONGetTextBinary<K>, JSONGetTextBuildError> {
+        JSONGetTextBinary::from_bytes(fs::read(path)?)
+    }
+
+    /// Get the `JSONGetText` instance. Its values cannot outlive this instance.
+    #[inline]
+    pub fn get(&self) -> &JSONGetText<'_, K> {
+        &self.ctx
+    }
+
+    /// Get the bytes of the binary catalog.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        // Safety: the bytes are never mutated or freed until the instance is dropped.
+        unsafe { self.bytes.as_ref() }
+    }
+
+    /// Copy the borrowed values and free the bytes, so that the `JSONGetText` instance can be used alone, e.g. in a [`JSONGetTextHandle`](crate::JSONGetTextHandle).
+    pub fn into_owned(self) -> JSONGetText<'static, K> {
+        let mut this = ManuallyDrop::new(self);
+
+        // Safety: `this` is not dropped, so `ctx` is taken once, and the bytes are freed after the owned copy is made.
+        let ctx = unsafe { ManuallyDrop::take(&mut this.ctx) }.into_owned();
+
+        drop(unsafe { Box::from_raw(this.bytes.as_ptr()) });
+
+        ctx
+    }
+}
+
+impl<K: LocaleKey> Drop for JSONGetTextBinary<K> {
+    #[inline]
+    fn drop(&mut self) {
+        // Safety: `ctx` is dropped before the bytes it borrows, and neither is used again.
+        unsafe {
+            ManuallyDrop::drop(&mut self.ctx);
+
+            drop(Box::from_raw(self.bytes.as_ptr()));
+        }
+    }
+}
+
+impl<K: LocaleKey> Debug for JSONGetTextBinary<K> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JSONGetTextBinary").field("ctx", self.get()).finish()
+    }
+}
@@ -4,15 +4,16 @@ use std::{
     io,
 };
 
-use crate::{serde_json::Error as JSONError, Key};
+use crate::{serde_json::Error as JSONError, JSONGetTextFormatError};
 
 #[derive(Debug)]
 pub enum JSONGetTextBuildError {
     DefaultKeyNotFound,
-    TextInKeyNotInDefaultKey { key: Key, text: String },
-    DuplicatedKey(Key),
+    TextInKeyNotInDefaultKey { key: String, text: String },
+    DuplicatedKey(String),
     IOError(io::Error),
     SerdeJSONError(JSONError),
+    FormatError(JSONGetTextFormatError),
 }
 
 impl Display for JSONGetTextBuildError {
@@ -32,6 +33,7 @@ impl Display for JSONGetTextBuildError {
             JSONGetTextBuildError::DuplicatedKey(key) => Display::fmt(key, f),
             JSONGetTextBuildError::IOError(err) => Display::fmt(err, f),
             JSONGetTextBuildError::SerdeJSONError(err) => Display::fmt(err, f),
+            JSONGetTextBuildError::FormatError(err) => Display::fmt(err, f),
         }
     }
 }
@@ -51,3 +53,10 @@ impl From<JSONError> for JSONGetTextBuildError {
         JSONGetTextBuildError::SerdeJSONError(v)
     }
 }
+
+impl From<JSONGetTextFormatError> for JSONGetTextBuildError {
+    #[inline]
+    fn from(v: JSONGetTextFormatError) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::FormatError(v)
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    ARBMetadata, Context, IntoLocaleKey, JSONGetText, JSONGetTextBuildError, JSONGetTextValue,
+    LocaleKey, MissingTextHandler, PseudoLocaleOptions,
+};
+
+/// To build a JSONGetText instance, this struct can help you do that step by step.
+#[derive(Debug, Clone)]
+pub struct JSONGetTextBuilder<'a, K: LocaleKey = String> {
+    default_key:  K,
+    context:      Context<'a, K>,
+    aliases:      HashMap<K, K>,
+    sources:      HashMap<K, PathBuf>,
+    descriptions: HashMap<String, String>,
+    handler:      Option<Arc<dyn MissingTextHandler>>,
+}
+
+impl<'a, K: LocaleKey> JSONGetTextBuilder<'a, K> {
+    /// Create a new `JSONGetTextBuilder` instance. You need to decide your default key at the stage.
+    #[inline]
+    pub fn new<D: IntoLocaleKey<Key = K>>(default_key: D) -> JSONGetTextBuilder<'a, K> {
+        JSONGetTextBuilder {
+            default_key:  default_key.into_locale_key().normalize(),
+            context:      HashMap::new(),
+            aliases:      HashMap::new(),
+            sources:      HashMap::new(),
+            descriptions: HashMap::new(),
+            handler:      None,
+        }
+    }
+
+    /// Add a JSON string to the context for a specify key. The JSON string must represent a map object (key-value). Strings, including the ones in objects and arrays, are borrowed from the JSON string if possible.
+    pub fn add_json<Q: IntoLocaleKey<Key = K>, J: AsRef<str> + ?Sized>(
+        &mut self,
+        key: Q,
+        json: &'a J,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        let map: HashMap<String, JSONGetTextValue<'a>> = serde_json::from_str(json.as_ref())?;
+
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+
+    /// Add a JSON string to the context for a specify key. The JSON string must represent a map object (key-value). The string is copied into one shared buffer which all the texts point to.
+    pub fn add_json_owned<Q: IntoLocaleKey<Key = K>, J: AsRef<str>>(
+        &mut self,
+        key: Q,
+        json: J,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        let map = crate::parse_shared_json_map(&Arc::from(json.as_ref()))?;
+
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+
+    /// Add a JSON file to the context for a specify key. The JSON file must represent a map object (key-value). The file is read into one shared buffer which all the texts point to. The path is recorded as the source of the key, see [`JSONGetText::lookup`](crate::JSONGetText::lookup).
+    pub fn add_json_file<Q: IntoLocaleKey<Key = K>, P: AsRef<Path>>(
+        &mut self,
+        key: Q,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        let path = path.as_ref();
+
+        let map = crate::parse_shared_json_map(&Arc::from(fs::read_to_string(path)?))?;
+
+        self.sources.insert(key.clone(), path.to_path_buf());
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+
+    /// Add any serializable value to the context for a specify key. The value must represent a map object (key-value).
+    pub fn add_serialize<Q: IntoLocaleKey<Key = K>, S: Serialize>(
+        &mut self,
+        key: Q,
+        value: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        let value: Value = serde_json::to_value(value)?;
+
+        match value {
+            Value::Object(value) => {
+                let mut map: HashMap<String, JSONGetTextValue<'static>> =
+                    HashMap::with_capacity(value.len());
+
+                for (k, v) in value {
+                    map.insert(k, JSONGetTextValue::from_json_value(v));
+                }
+
+                self.context.insert(key, map);
+
+                Ok(self)
+            },
+            _ => {
+                serde_json::from_str::<Map<String, Value>>("\"MagicLen\"")?;
+
+                unreachable!()
+            },
+        }
+    }
+
+    /// Add a map to the context.
+    pub fn add_map<Q: IntoLocaleKey<Key = K>>(
+        &mut self,
+        key: Q,
+        map: HashMap<String, JSONGetTextValue<'a>>,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+
+    /// Add a Flutter ARB document to the context for a specify key. See [`parse_arb`](crate::parse_arb). With `ARBMetadata::Description`, the descriptions in the document are also collected, and a later document overrides the descriptions of the same texts. See [`JSONGetText::get_description`].
+    pub fn add_arb<Q: IntoLocaleKey<Key = K>, S: AsRef<str>>(
+        &mut self,
+        key: Q,
+        json: S,
+        metadata: ARBMetadata,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into_locale_key().normalize();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_key_string()));
+        }
+
+        let mut descriptions = HashMap::new();
+
+        let map = crate::parse_arb_document(json, match metadata {
+            ARBMetadata::Strip => None,
+            ARBMetadata::Description => Some(&mut descriptions),
+        })?;
+
+        self.context.insert(key, map);
+
+        // the descriptions are only merged after the document is added
+        self.descriptions.extend(descriptions);
+
+        Ok(self)
+    }
+
+    /// Add an i18next JSON document to the context for a specify key. See [`parse_i18next`](crate::parse_i18next).
+    pub fn add_i18next<Q: IntoLocaleKey<Key = K>, S: AsRef<str>>(
+        &mut self,
+        key: Q,
+        json: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let map = crate::parse_i18next(json)?;
+
+        self.add_map(key, map)
+    }
+
+    /// Add an Android `strings.xml` resource document to the context for a specify key. See [`parse_android_strings_xml`](crate::parse_android_strings_xml).
+    #[cfg(feature = "android")]
+    pub fn add_android_strings_xml<Q: IntoLocaleKey<Key = K>, S: AsRef<str>>(
+        &mut self,
+        key: Q,
+        xml: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let map = crate::parse_android_strings_xml(xml)?;
+
+        self.add_map(key, map)
+    }
+
+    /// Add an Apple `.strings` document, and optionally its `.stringsdict` document, to the context for a specify key. See [`parse_apple_strings`](crate::parse_apple_strings) and [`parse_apple_stringsdict`](crate::parse_apple_stringsdict).
+    #[cfg(feature = "apple")]
+    pub fn add_apple_strings<Q: IntoLocaleKey<Key = K>, S: AsRef<str>>(
+        &mut self,
+        key: Q,
+        strings: S,
+        stringsdict: Option<&str>,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let mut map = crate::parse_apple_strings(strings)?;
+
+        if let Some(stringsdict) = stringsdict {
+            map.extend(crate::parse_apple_stringsdict(stringsdict)?);
+        }
+
+        self.add_map(key, map)
+    }
+
+    /// Add a pseudo-locale generated from the texts of the default key for a specify key, e.g. `en_XA` with [`PseudoLocaleOptions::accented`] or `ar_XB` with [`PseudoLocaleOptions::mirrored`]. The default key must be added before. See [`pseudo_localize`](crate::pseudo_localize).
+    pub fn add_pseudo_locale<Q: IntoLocaleKey<Key = K>>(
+        &mut self,
+        key: Q,
+        options: &PseudoLocaleOptions,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let default_map =
+            self.context.get(&self.default_key).ok_or(JSONGetTextBuildError::DefaultKeyNotFound)?;
+
+        let map = crate::pseudo_localize_map(
+            default_map.iter().map(|(text, value)| (text.as_str(), value)),
+            options,
+        );
+
+        self.add_map(key, map)
+    }
+
+    /// Add an alias of a key, e.g. `iw` for `he`. A key which does not exist is looked up as its alias target before falling back. Both keys are normalized.
+    pub fn add_alias<A: IntoLocaleKey<Key = K>, Q: IntoLocaleKey<Key = K>>(
+        &mut self,
+        alias: A,
+        key: Q,
+    ) -> &mut Self {
+        self.aliases.insert(alias.into_locale_key().normalize(), key.into_locale_key().normalize());
+
+        self
+    }
+
+    /// Set a hook which is called when a text is not found or not translated. See [`JSONGetText::set_missing_text_handler`].
+    #[inline]
+    pub fn set_missing_text_handler<H: MissingTextHandler + 'static>(
+        &mut self,
+        handler: H,
+    ) -> &mut Self {
+        self.handler = Some(Arc::new(handler));
+
+        self
+    }
+
+    /// Build a `JSONGetText` instance.
+    pub fn build(self) -> Result<JSONGetText<'a, K>, JSONGetTextBuildError> {
+        let mut ctx = JSONGetText::from_context_with_default_key(self.default_key, self.context)?;
+
+        ctx.set_aliases(self.aliases);
+        ctx.set_sources(self.sources);
+        ctx.set_descriptions(self.descriptions);
+        ctx.set_handler(self.handler);
+
+        Ok(ctx)
+    }
+}
+
+impl<'a> From<String> for JSONGetTextBuilder<'a, String> {
+    #[inline]
+    fn from(v: String) -> JSONGetTextBuilder<'a, String> {
+        JSONGetTextBuilder::new(v)
+    }
+}
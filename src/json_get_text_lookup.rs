@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use crate::{JSONGetTextValue, LocaleKey};
+
+/// The value of a text with where it comes from. It is returned by [`JSONGetText::lookup`](crate::JSONGetText::lookup).
+#[derive(Debug, Clone)]
+pub struct JSONGetTextLookup<'a, K: LocaleKey = String> {
+    /// The value of the text.
+    pub value:               JSONGetTextValue<'a>,
+    /// The requested key (normalized).
+    pub requested_key:       K,
+    /// The key whose texts are used after aliases and fallback keys are resolved. It is the default key if the requested key does not exist.
+    pub resolved_key:        &'a K,
+    /// Whether the text is not translated for the resolved key and the value is copied from the default key.
+    pub copied_from_default: bool,
+    /// The file the value is loaded from, if it is added by a file.
+    pub source:              Option<&'a Path>,
+}
+
+impl<'a, K: LocaleKey> JSONGetTextLookup<'a, K> {
+    /// Returns `true` if the requested key does not exist and another key is used.
+    #[inline]
+    pub fn is_key_fallback(&self) -> bool {
+        self.resolved_key != &self.requested_key
+    }
+
+    /// Returns `true` if the value does not come from the requested key.
+    #[inline]
+    pub fn is_fallback(&self) -> bool {
+        self.copied_from_default || self.is_key_fallback()
+    }
+}
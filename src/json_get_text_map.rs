@@ -0,0 +1,264 @@
+use std::collections::{hash_map, HashMap};
+
+use crate::{
+    interned_context::{LocaleValues, TextIds},
+    JSONGetTextValue,
+};
+
+/// A view of the text map of a key. Locales only store their own texts, and the texts which are not translated for the key are looked up from the default key transparently, so this view behaves like a complete map.
+#[derive(Debug, Clone, Copy)]
+pub struct JSONGetTextMap<'b, 'a> {
+    inner: Inner<'b, 'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Inner<'b, 'a> {
+    Map(&'b HashMap<String, JSONGetTextValue<'a>>),
+    Interned {
+        ids:            &'b TextIds,
+        values:         Option<&'b LocaleValues<'a>>,
+        default_values: &'b [JSONGetTextValue<'a>],
+    },
+}
+
+/// Get the value which a key has on its own.
+#[inline]
+fn own_value<'b, 'a>(
+    values: Option<&'b LocaleValues<'a>>,
+    default_values: &'b [JSONGetTextValue<'a>],
+    i: usize,
+) -> Option<&'b JSONGetTextValue<'a>> {
+    match values? {
+        LocaleValues::Default => Some(&default_values[i]),
+        LocaleValues::Own(values) => values
+            .binary_search_by_key(&(i as u32), |(j, _)| *j)
+            .ok()
+            .map(|position| &values[position].1),
+    }
+}
+
+impl<'b, 'a> JSONGetTextMap<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(
+        ids: &'b TextIds,
+        values: Option<&'b LocaleValues<'a>>,
+        default_values: &'b [JSONGetTextValue<'a>],
+    ) -> JSONGetTextMap<'b, 'a> {
+        JSONGetTextMap {
+            inner: Inner::Interned {
+                ids,
+                values,
+                default_values,
+            },
+        }
+    }
+
+    /// Get the value of a text. The value of the default key is returned if the text is not translated.
+    #[inline]
+    pub fn get<T: AsRef<str>>(&self, text: T) -> Option<&'b JSONGetTextValue<'a>> {
+        match self.inner {
+            Inner::Map(map) => map.get(text.as_ref()),
+            Inner::Interned {
+                ids,
+                values,
+                default_values,
+            } => {
+                let i = ids.get_index(text.as_ref())?;
+
+                own_value(values, default_values, i).or(Some(&default_values[i]))
+            },
+        }
+    }
+
+    /// Returns `true` if the map contains a value for the specified text.
+    #[inline]
+    pub fn contains_key<T: AsRef<str>>(&self, text: T) -> bool {
+        match self.inner {
+            Inner::Map(map) => map.contains_key(text.as_ref()),
+            Inner::Interned {
+                ids, ..
+            } => ids.get_index(text.as_ref()).is_some(),
+        }
+    }
+
+    /// Returns `true` if the text has its own value for this key instead of falling back to the default key.
+    #[inline]
+    pub fn is_translated<T: AsRef<str>>(&self, text: T) -> bool {
+        match self.inner {
+            Inner::Map(map) => map.contains_key(text.as_ref()),
+            Inner::Interned {
+                ids,
+                values,
+                default_values,
+            } => match ids.get_index(text.as_ref()) {
+                Some(i) => own_value(values, default_values, i).is_some(),
+                None => false,
+            },
+        }
+    }
+
+    /// An iterator visiting the texts which have their own values for this key, in arbitrary order. Nothing is visited if the key does not exist.
+    #[inline]
+    pub fn translated(&self) -> JSONGetTextMapIter<'b, 'a> {
+        self.iter_inner(true)
+    }
+
+    /// Get the number of texts.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.inner {
+            Inner::Map(map) => map.len(),
+            Inner::Interned {
+                ids, ..
+            } => ids.len(),
+        }
+    }
+
+    /// Returns `true` if there is no text.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all texts in arbitrary order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &'b str> {
+        self.iter().map(|(text, _)| text)
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &'b JSONGetTextValue<'a>> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// An iterator visiting all text-value pairs in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> JSONGetTextMapIter<'b, 'a> {
+        self.iter_inner(false)
+    }
+
+    #[inline]
+    fn iter_inner(&self, translated_only: bool) -> JSONGetTextMapIter<'b, 'a> {
+        let inner = match self.inner {
+            Inner::Map(map) => IterInner::Map(map.iter()),
+            Inner::Interned {
+                ids,
+                values,
+                default_values,
+            } => IterInner::Interned {
+                ids,
+                values,
+                default_values,
+                translated_only,
+                index: 0,
+                position: 0,
+            },
+        };
+
+        JSONGetTextMapIter {
+            inner,
+        }
+    }
+}
+
+impl<'b, 'a> From<&'b HashMap<String, JSONGetTextValue<'a>>> for JSONGetTextMap<'b, 'a> {
+    #[inline]
+    fn from(map: &'b HashMap<String, JSONGetTextValue<'a>>) -> Self {
+        JSONGetTextMap {
+            inner: Inner::Map(map)
+        }
+    }
+}
+
+impl<'b, 'a> IntoIterator for JSONGetTextMap<'b, 'a> {
+    type IntoIter = JSONGetTextMapIter<'b, 'a>;
+    type Item = (&'b str, &'b JSONGetTextValue<'a>);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the text-value pairs of a `JSONGetTextMap`.
+#[derive(Debug, Clone)]
+pub struct JSONGetTextMapIter<'b, 'a> {
+    inner: IterInner<'b, 'a>,
+}
+
+#[derive(Debug, Clone)]
+enum IterInner<'b, 'a> {
+    Map(hash_map::Iter<'b, String, JSONGetTextValue<'a>>),
+    Interned {
+        ids:             &'b TextIds,
+        values:          Option<&'b LocaleValues<'a>>,
+        default_values:  &'b [JSONGetTextValue<'a>],
+        translated_only: bool,
+        /// The index of the next text.
+        index:           usize,
+        /// The position of the next own value if the key has its own values.
+        position:        usize,
+    },
+}
+
+impl<'b, 'a> Iterator for JSONGetTextMapIter<'b, 'a> {
+    type Item = (&'b str, &'b JSONGetTextValue<'a>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterInner::Map(iter) => iter.next().map(|(text, value)| (text.as_str(), value)),
+            IterInner::Interned {
+                ids,
+                values,
+                default_values,
+                translated_only,
+                index,
+                position,
+            } => {
+                let ids: &'b TextIds = ids;
+                let default_values: &'b [JSONGetTextValue<'a>] = default_values;
+
+                match *values {
+                    Some(LocaleValues::Default) => {
+                        let i = *index;
+
+                        let value = default_values.get(i)?;
+
+                        *index += 1;
+
+                        Some((ids.get_text(i), value))
+                    },
+                    Some(LocaleValues::Own(own_values)) if *translated_only => {
+                        let (i, value) = own_values.get(*position)?;
+
+                        *position += 1;
+
+                        Some((ids.get_text(*i as usize), value))
+                    },
+                    _ if *translated_only => None,
+                    values => {
+                        let i = *index;
+
+                        let default_value = default_values.get(i)?;
+
+                        *index += 1;
+
+                        if let Some(LocaleValues::Own(own_values)) = values {
+                            if let Some((j, value)) = own_values.get(*position) {
+                                if *j as usize == i {
+                                    *position += 1;
+
+                                    return Some((ids.get_text(i), value));
+                                }
+                            }
+                        }
+
+                        Some((ids.get_text(i), default_value))
+                    },
+                }
+            },
+        }
+    }
+}
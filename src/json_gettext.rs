@@ -0,0 +1,595 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{self, Write},
+    marker::PhantomData,
+    panic::Location,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use regex::Regex;
+
+use crate::{
+    interned_context::{InternedContext, LocaleValues},
+    locale_key::RequestedKey,
+    AsLocaleKey, DisplayKey, HTMLText, IntoLocaleKey, JSONGetTextBinary, JSONGetTextBuildError,
+    JSONGetTextBuilder, JSONGetTextFormatError, JSONGetTextLookup, JSONGetTextMap,
+    JSONGetTextValue, LocaleKey, MissingText, MissingTextHandler, MissingTextKind,
+};
+
+/// Texts of keys. Each key has a map of texts.
+pub type Context<'a, K = String> = HashMap<K, HashMap<String, JSONGetTextValue<'a>>>;
+
+/// A wrapper for context and a default key. **Keys** are usually considered as locales. The type of keys is `K`, which is `String` by default.
+#[derive(Debug)]
+pub struct JSONGetText<'a, K: LocaleKey = String> {
+    default_key:  K,
+    context:      InternedContext<'a, K>,
+    aliases:      HashMap<K, K>,
+    sources:      HashMap<K, PathBuf>,
+    descriptions: HashMap<String, String>,
+    handler:      Option<Arc<dyn MissingTextHandler>>,
+}
+
+impl<'a, K: LocaleKey> JSONGetText<'a, K> {
+    /// Create a new `JSONGetTextBuilder` instance. You need to decide your default key at the stage. The type of keys is decided by the default key, e.g. `"en_US"` creates a builder for `String` keys.
+    #[inline]
+    pub fn build<D: IntoLocaleKey<Key = K>>(default_key: D) -> JSONGetTextBuilder<'a, K> {
+        JSONGetTextBuilder::new(default_key)
+    }
+
+    /// Create a new JSONGetText instance with context and a default key. Every text in the context must exist in the default key. Texts which are not translated are not copied from the default key, but looked up from it when needed.
+    pub(crate) fn from_context_with_default_key<T: AsRef<str> + Into<Arc<str>>>(
+        default_key: K,
+        context: HashMap<K, HashMap<T, JSONGetTextValue<'a>>>,
+    ) -> Result<JSONGetText<'a, K>, JSONGetTextBuildError> {
+        if !context.contains_key(&default_key) {
+            return Err(JSONGetTextBuildError::DefaultKeyNotFound);
+        }
+
+        let context =
+            InternedContext::new(default_key.clone(), context).map_err(|(key, text)| {
+                JSONGetTextBuildError::TextInKeyNotInDefaultKey {
+                    key: key.to_key_string(),
+                    text,
+                }
+            })?;
+
+        Ok(JSONGetText {
+            default_key,
+            context,
+            aliases: HashMap::new(),
+            sources: HashMap::new(),
+            descriptions: HashMap::new(),
+            handler: None,
+        })
+    }
+
+    #[inline]
+    pub(crate) fn set_aliases(&mut self, aliases: HashMap<K, K>) {
+        self.aliases = aliases;
+    }
+
+    #[inline]
+    pub(crate) fn set_sources(&mut self, sources: HashMap<K, PathBuf>) {
+        self.sources = sources;
+    }
+
+    #[inline]
+    pub(crate) fn set_descriptions(&mut self, descriptions: HashMap<String, String>) {
+        self.descriptions = descriptions;
+    }
+
+    #[inline]
+    pub(crate) fn set_handler(&mut self, handler: Option<Arc<dyn MissingTextHandler>>) {
+        self.handler = handler;
+    }
+
+    /// Set a hook which is called when a text is not found or not translated by `get_text`, `get_text_with_key`, `get_multiple_text` or `get_multiple_text_with_key`. See [`MissingTextHandler`].
+    #[inline]
+    pub fn set_missing_text_handler<H: MissingTextHandler + 'static>(&mut self, handler: H) {
+        self.handler = Some(Arc::new(handler));
+    }
+
+    /// Remove the hook of missing texts.
+    #[inline]
+    pub fn remove_missing_text_handler(&mut self) {
+        self.handler = None;
+    }
+
+    /// Get all keys in context. Keys are in their canonical forms.
+    pub fn get_keys(&self) -> Vec<&K> {
+        self.context.locales.keys().collect()
+    }
+
+    /// Returns `true` if the context contains a value for the specified key.
+    #[inline]
+    pub fn contains_key<Q: AsLocaleKey<K>>(&self, key: Q) -> bool {
+        key.get_from(&self.context.locales).is_some()
+    }
+
+    /// Get the default key.
+    #[inline]
+    pub fn get_default_key(&self) -> &K {
+        &self.default_key
+    }
+
+    /// Get the aliases of keys.
+    #[inline]
+    pub fn get_aliases(&self) -> &HashMap<K, K> {
+        &self.aliases
+    }
+
+    /// Get the description of a text, which is collected from the metadata of ARB documents. See [`ARBMetadata`](crate::ARBMetadata).
+    #[inline]
+    pub fn get_description<T: AsRef<str>>(&self, text: T) -> Option<&str> {
+        self.descriptions.get(text.as_ref()).map(|s| s.as_str())
+    }
+
+    /// Get the descriptions of texts.
+    #[inline]
+    pub fn get_descriptions(&self) -> &HashMap<String, String> {
+        &self.descriptions
+    }
+
+    /// Get a string map from context by a key. If the key does not exist, its alias target and then its fallback keys are tried, e.g. `zh-Hant-HK` falls back to `zh-Hant` and then `zh` for `LanguageIdentifier` keys. Texts which are not translated for the key are looked up from the default key.
+    #[inline]
+    pub fn get<Q: AsLocaleKey<K>>(&self, key: Q) -> JSONGetTextMap<'_, 'a> {
+        self.get_by_ref(&key)
+    }
+
+    #[inline]
+    fn get_by_ref<Q: AsLocaleKey<K>>(&self, key: &Q) -> JSONGetTextMap<'_, 'a> {
+        let locales = &self.context.locales;
+
+        let values = key
+            .get_from(locales)
+            .or_else(|| key.get_from(&self.aliases).and_then(|key| locales.get(key)))
+            .or_else(|| key.get_fallback_from(locales));
+
+        self.get_map(values)
+    }
+
+    /// Get the value of a text from a map and call the hook of missing texts if needed.
+    #[track_caller]
+    fn get_value<'b>(
+        &'b self,
+        map: &JSONGetTextMap<'b, 'a>,
+        key: &dyn Display,
+        text: &str,
+    ) -> Option<JSONGetTextValue<'b>> {
+        let value = map.get(text);
+
+        if let Some(handler) = self.handler.as_deref() {
+            let kind = match value {
+                None => Some(MissingTextKind::NotFound),
+                Some(_) if !map.is_translated(text) => Some(MissingTextKind::Untranslated),
+                Some(_) => None,
+            };
+
+            if let Some(kind) = kind {
+                let key = key.to_string();
+
+                let missing = MissingText {
+                    key: &key,
+                    text,
+                    kind,
+                    location: Location::caller(),
+                };
+
+                if let Some(value) = handler.handle(&missing) {
+                    return Some(value);
+                }
+            }
+        }
+
+        value.map(|v| v.clone_borrowed())
+    }
+
+    #[inline]
+    fn default_map(&self) -> JSONGetTextMap<'_, 'a> {
+        self.get_map(self.context.locales.get(&self.default_key))
+    }
+
+    #[inline]
+    fn get_map<'b>(&'b self, values: Option<&'b LocaleValues<'a>>) -> JSONGetTextMap<'b, 'a> {
+        JSONGetTextMap::new(&self.context.ids, values, &self.context.default_values)
+    }
+
+    /// Get the file which the texts of a key are loaded from, if the key is added by a file.
+    #[inline]
+    pub fn get_source<Q: AsLocaleKey<K>>(&self, key: Q) -> Option<&Path> {
+        key.get_from(&self.sources).map(PathBuf::as_path)
+    }
+
+    /// Find the key whose texts are used for a key, in the same order as `get`.
+    pub(crate) fn resolve_key(&self, key: &K) -> Option<&K> {
+        let locales = &self.context.locales;
+
+        if let Some((key, _)) = locales.get_key_value(key) {
+            return Some(key);
+        }
+
+        if let Some((key, _)) = self.aliases.get(key).and_then(|key| locales.get_key_value(key)) {
+            return Some(key);
+        }
+
+        let mut key = key.fallback()?;
+
+        loop {
+            if let Some((key, _)) = locales.get_key_value(&key) {
+                return Some(key);
+            }
+
+            key = key.fallback()?;
+        }
+    }
+
+    /// Get the text map of a key whose type is `K`, in the same order as `get`.
+    #[cfg(any(feature = "rocket", feature = "axum", feature = "actix"))]
+    #[inline]
+    pub(crate) fn get_by_key(&self, key: &K) -> JSONGetTextMap<'_, 'a> {
+        let resolved_key = self.resolve_key(key).unwrap_or(&self.default_key);
+
+        self.get_map(self.context.locales.get(resolved_key))
+    }
+
+    /// Get text from context with a specific key whose type is `K`, like `get_text_with_key`.
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    #[inline]
+    #[track_caller]
+    pub(crate) fn get_text_by_key(&self, key: &K, text: &str) -> Option<JSONGetTextValue<'_>> {
+        let map = self.get_by_key(key);
+
+        self.get_value(&map, &DisplayKey(key), text)
+    }
+
+    #[inline]
+    fn lookup_in(
+        &'a self,
+        requested_key: &K,
+        resolved_key: &'a K,
+        map: &JSONGetTextMap<'a, 'a>,
+        text: &str,
+    ) -> Option<JSONGetTextLookup<'a, K>> {
+        let value = map.get(text)?;
+
+        let copied_from_default = !map.is_translated(text);
+
+        let source_key = if copied_from_default { &self.default_key } else { resolved_key };
+
+        Some(JSONGetTextLookup {
+            value: value.clone_borrowed(),
+            requested_key: requested_key.clone(),
+            resolved_key,
+            copied_from_default,
+            source: self.sources.get(source_key).map(PathBuf::as_path),
+        })
+    }
+
+    /// Get text from context with a specific key, and where the value comes from, including whether the key falls back to another key and whether the text is copied from the default key. `None` is also returned if the key is not a valid key.
+    pub fn lookup<Q: AsLocaleKey<K>, T: AsRef<str>>(
+        &'a self,
+        key: Q,
+        text: T,
+    ) -> Option<JSONGetTextLookup<'a, K>> {
+        let key = key.to_locale_key()?;
+        let resolved_key = self.resolve_key(&key).unwrap_or(&self.default_key);
+        let map = self.get_map(self.context.locales.get(resolved_key));
+
+        self.lookup_in(&key, resolved_key, &map, text.as_ref())
+    }
+
+    /// Get multiple text from context with a specific key, and where the values come from. See [`lookup`](Self::lookup).
+    pub fn lookup_multiple<'b, Q: AsLocaleKey<K>, T: AsRef<str> + ?Sized>(
+        &'a self,
+        key: Q,
+        text_array: &[&'b T],
+    ) -> Option<HashMap<&'b str, JSONGetTextLookup<'a, K>>> {
+        let key = key.to_locale_key()?;
+        let resolved_key = self.resolve_key(&key).unwrap_or(&self.default_key);
+        let map = self.get_map(self.context.locales.get(resolved_key));
+
+        let mut new_map = HashMap::new();
+
+        for &text in text_array.iter() {
+            let text = text.as_ref();
+            let lookup = self.lookup_in(&key, resolved_key, &map, text)?;
+            new_map.insert(text, lookup);
+        }
+
+        Some(new_map)
+    }
+
+    /// Get filtered text from context with a specific key by a Regex instance, and where the values come from. See [`lookup`](Self::lookup).
+    pub fn lookup_filtered<Q: AsLocaleKey<K>>(
+        &'a self,
+        key: Q,
+        regex: &Regex,
+    ) -> Option<HashMap<&'a str, JSONGetTextLookup<'a, K>>> {
+        let key = key.to_locale_key()?;
+        let resolved_key = self.resolve_key(&key).unwrap_or(&self.default_key);
+        let map = self.get_map(self.context.locales.get(resolved_key));
+
+        let mut new_map = HashMap::new();
+
+        for text in map.keys() {
+            if !regex.is_match(text) {
+                continue;
+            }
+
+            if let Some(lookup) = self.lookup_in(&key, resolved_key, &map, text) {
+                new_map.insert(text, lookup);
+            }
+        }
+
+        Some(new_map)
+    }
+
+    /// Get text from context.
+    #[inline]
+    #[track_caller]
+    pub fn get_text<T: AsRef<str>>(&'a self, text: T) -> Option<JSONGetTextValue<'a>> {
+        let map = self.default_map();
+
+        self.get_value(&map, &DisplayKey(&self.default_key), text.as_ref())
+    }
+
+    /// Get text from context with a specific key.
+    #[inline]
+    #[track_caller]
+    pub fn get_text_with_key<Q: AsLocaleKey<K>, T: AsRef<str>>(
+        &'a self,
+        key: Q,
+        text: T,
+    ) -> Option<JSONGetTextValue<'a>> {
+        let map = self.get_by_ref(&key);
+
+        self.get_value(&map, &RequestedKey::<K, Q>(&key, PhantomData), text.as_ref())
+    }
+
+    /// Get multiple text from context. The output map is usually used for serialization.
+    #[track_caller]
+    pub fn get_multiple_text<'b, T: AsRef<str> + ?Sized>(
+        &self,
+        text_array: &[&'b T],
+    ) -> Option<HashMap<&'b str, JSONGetTextValue<'_>>> {
+        let map = self.default_map();
+
+        let mut new_map = HashMap::new();
+
+        for &text in text_array.iter() {
+            let text = text.as_ref();
+            let value = self.get_value(&map, &DisplayKey(&self.default_key), text)?;
+            new_map.insert(text, value);
+        }
+
+        Some(new_map)
+    }
+
+    /// Get multiple text from context with a specific key. The output map is usually used for serialization.
+    #[track_caller]
+    pub fn get_multiple_text_with_key<'b, Q: AsLocaleKey<K>, T: AsRef<str> + ?Sized>(
+        &'a self,
+        key: Q,
+        text_array: &[&'b T],
+    ) -> Option<HashMap<&'b str, JSONGetTextValue<'a>>> {
+        let map = self.get_by_ref(&key);
+
+        let mut new_map = HashMap::new();
+
+        for &text in text_array.iter() {
+            let text = text.as_ref();
+            let value = self.get_value(&map, &RequestedKey::<K, Q>(&key, PhantomData), text)?;
+            new_map.insert(text, value);
+        }
+
+        Some(new_map)
+    }
+
+    /// Get filtered text from context by a Regex instance. The output map is usually used for serialization.
+    pub fn get_filtered_text(
+        &'a self,
+        regex: &Regex,
+    ) -> Option<HashMap<&'a str, JSONGetTextValue<'a>>> {
+        let map = self.default_map();
+
+        let mut new_map = HashMap::new();
+
+        for (key, value) in map.iter() {
+            if !regex.is_match(key) {
+                continue;
+            }
+            new_map.insert(key, value.clone_borrowed());
+        }
+
+        Some(new_map)
+    }
+
+    /// Get filtered text from context with a specific key by a Regex instance. The output map is usually used for serialization.
+    pub fn get_filtered_text_with_key<Q: AsLocaleKey<K>>(
+        &'a self,
+        key: Q,
+        regex: &Regex,
+    ) -> Option<HashMap<&'a str, JSONGetTextValue<'a>>> {
+        let map = self.get(key);
+
+        let mut new_map = HashMap::new();
+
+        for (key, value) in map.iter() {
+            if !regex.is_match(key) {
+                continue;
+            }
+            new_map.insert(key, value.clone_borrowed());
+        }
+
+        Some(new_map)
+    }
+
+    /// Render a text as HTML. The text is trusted markup and only the arguments are escaped, see [`format_html`](crate::format_html). With the `markdown` feature, a text whose ID is flagged by [`is_markdown_text_id`](crate::is_markdown_text_id) is rendered from Markdown. `None` is returned if the text is not found or not a string.
+    #[inline]
+    #[track_caller]
+    pub fn render_html<T: AsRef<str>>(
+        &'a self,
+        text: T,
+        args: &[(&str, &dyn Display)],
+    ) -> Option<HTMLText> {
+        let text = text.as_ref();
+
+        let value = self.get_text(text)?;
+
+        Some(crate::html::render_text_html(text, value.as_str()?, args))
+    }
+
+    /// Render a text with a specific key as HTML. See [`render_html`](Self::render_html).
+    #[inline]
+    #[track_caller]
+    pub fn render_html_with_key<Q: AsLocaleKey<K>, T: AsRef<str>>(
+        &'a self,
+        key: Q,
+        text: T,
+        args: &[(&str, &dyn Display)],
+    ) -> Option<HTMLText> {
+        let text = text.as_ref();
+
+        let value = self.get_text_with_key(key, text)?;
+
+        Some(crate::html::render_text_html(text, value.as_str()?, args))
+    }
+
+    /// Generate an Android `strings.xml` resource document from the text map of a specific key.
+    #[cfg(feature = "android")]
+    #[inline]
+    pub fn to_android_strings_xml<Q: AsLocaleKey<K>>(
+        &self,
+        key: Q,
+    ) -> Result<String, JSONGetTextFormatError> {
+        crate::to_android_strings_xml(self.get(key))
+    }
+
+    /// Generate an Apple `.strings` document from the text map of a specific key. Plural objects are not included.
+    #[cfg(feature = "apple")]
+    #[inline]
+    pub fn to_apple_strings<Q: AsLocaleKey<K>>(
+        &self,
+        key: Q,
+    ) -> Result<String, JSONGetTextFormatError> {
+        crate::to_apple_strings(self.get(key))
+    }
+
+    /// Generate an Apple `.stringsdict` document from the plural objects in the text map of a specific key.
+    #[cfg(feature = "apple")]
+    #[inline]
+    pub fn to_apple_stringsdict<Q: AsLocaleKey<K>>(
+        &self,
+        key: Q,
+    ) -> Result<String, JSONGetTextFormatError> {
+        crate::to_apple_stringsdict(self.get(key))
+    }
+
+    /// Write this `JSONGetText` instance in the binary catalog format, which can be loaded much faster than JSON. Aliases, source paths and descriptions are also written. See [`from_binary`](Self::from_binary).
+    pub fn write_binary<W: Write>(&self, writer: W) -> Result<(), io::Error> {
+        let sources = self
+            .sources
+            .iter()
+            .map(|(key, path)| {
+                let path = path.to_str().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "A source path is not valid UTF-8.")
+                })?;
+
+                Ok((key.to_key_string(), path))
+            })
+            .collect::<Result<Vec<_>, io::Error>>()?;
+
+        let catalog = crate::BinaryCatalog {
+            default_key: self.default_key.to_key_string(),
+            aliases: self
+                .aliases
+                .iter()
+                .map(|(alias, key)| (alias.to_key_string(), key.to_key_string()))
+                .collect(),
+            sources,
+            descriptions: self
+                .descriptions
+                .iter()
+                .map(|(text, description)| (text.as_str(), description.as_str()))
+                .collect(),
+            locales: self
+                .context
+                .locales
+                .iter()
+                .map(|(key, values)| (key.to_key_string(), self.get_map(Some(values))))
+                .collect(),
+        };
+
+        crate::write_binary_catalog(writer, catalog)
+    }
+
+    /// Convert this `JSONGetText` instance to the binary catalog format. See [`from_binary`](Self::from_binary).
+    #[inline]
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        self.write_binary(&mut bytes).unwrap();
+
+        bytes
+    }
+
+    /// Load a `JSONGetText` instance from the binary catalog format in one pass. The checksum is verified, and text values, including the strings in objects and arrays, are borrowed from `bytes` without being copied, so `bytes` can be a `&'static [u8]` (e.g. from `include_bytes!`) or a memory-mapped file. Text ids are interned once, and the aliases, source paths and descriptions are restored.
+    pub fn from_binary(bytes: &'a [u8]) -> Result<JSONGetText<'a, K>, JSONGetTextBuildError> {
+        let decoded = crate::read_binary_catalog(bytes)?;
+
+        let parse_key = |key: &str| {
+            K::parse_key(key).ok_or_else(|| {
+                JSONGetTextFormatError::InvalidFormat(format!("`{}` is not a valid key.", key))
+            })
+        };
+
+        let default_key = parse_key(decoded.default_key)?;
+
+        let mut context = HashMap::with_capacity(decoded.locales.len());
+
+        for (key, map) in decoded.locales {
+            context.insert(parse_key(key)?, map);
+        }
+
+        let mut ctx = JSONGetText::from_context_with_default_key(default_key, context)?;
+
+        for (alias, key) in decoded.aliases {
+            ctx.aliases.insert(parse_key(alias)?, parse_key(key)?);
+        }
+
+        for (key, path) in decoded.sources {
+            ctx.sources.insert(parse_key(key)?, PathBuf::from(path));
+        }
+
+        ctx.descriptions = decoded
+            .descriptions
+            .into_iter()
+            .map(|(text, description)| (String::from(text), String::from(description)))
+            .collect();
+
+        Ok(ctx)
+    }
+
+    /// Load a binary catalog file. The returned [`JSONGetTextBinary`] instance owns the bytes of the file and borrows text values from them. See [`from_binary`](Self::from_binary).
+    #[inline]
+    pub fn from_binary_file<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<JSONGetTextBinary<K>, JSONGetTextBuildError> {
+        JSONGetTextBinary::open(path)
+    }
+
+    /// Convert all borrowed values to owned ones, so that the instance no longer borrows its sources.
+    pub fn into_owned(self) -> JSONGetText<'static, K> {
+        JSONGetText {
+            default_key:  self.default_key,
+            context:      self.context.into_owned(),
+            aliases:      self.aliases,
+            sources:      self.sources,
+            descriptions: self.descriptions,
+            handler:      self.handler,
+        }
+    }
+}
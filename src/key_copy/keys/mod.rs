@@ -1,15 +0,0 @@
-#[cfg(feature = "language_region_pair")]
-mod language_region_pair;
-
-#[cfg(feature = "language")]
-mod language;
-
-#[cfg(feature = "region")]
-mod region;
-
-#[cfg(feature = "language")]
-pub use language::*;
-#[cfg(feature = "language_region_pair")]
-pub use language_region_pair::*;
-#[cfg(feature = "region")]
-pub use region::*;
@@ -1,5 +0,0 @@
-mod json_get_text_fairing;
-mod json_get_text_manager;
-
-pub use json_get_text_fairing::*;
-pub use json_get_text_manager::*;
@@ -42,11 +42,15 @@ use json_gettext::JSONGetTextManager;
 
 #[get("/")]
 fn index(ctx: &State<JSONGetTextManager>) -> Redirect {
+    let ctx = ctx.share();
+
     Redirect::temporary(uri!(hello(lang = ctx.get_default_key())))
 }
 
 #[get("/<lang>")]
 fn hello(ctx: &State<JSONGetTextManager>, lang: String) -> String {
+    let ctx = ctx.share();
+
     format!("Ron: {}", get_text!(ctx, lang, "hello").unwrap().as_str().unwrap())
 }
 
@@ -62,20 +66,22 @@ fn rocket() -> _ {
 }
 ```
 
-If you are not using the `release` profile, `JSONGetTextManager` can reload the json files automatically if needed.
+If you are not using the `release` profile, `JSONGetTextManager` can reload the json files automatically if needed. `share` returns the current `JSONGetText` as an `Arc`, so a request keeps using the same catalog even if the files are reloaded meanwhile.
 
 ## `unic-langid` Support
 
-Since string comparison could be slow, the `language_region_pair` feature, the `language` feature or the `region` feature can be enabled to change key's type to `(Language, Option<Region>)`, `Language` or `Region` respectively where `Language` and `Region` structs are in the `unic-langid` crate.
+`JSONGetText<K>` is generic over the type of keys, which implements the `LocaleKey` trait. Keys are `String`s by default. Since string comparison could be slow, the `langid` feature can be enabled to use `Language`, `Region`, `(Language, Option<Region>)` or a full `LanguageIdentifier` from the `unic-langid` crate as keys instead. The feature only adds implementations, so different crates in the same project can use different key types. The `language_region_pair`, `language` and `region` features are kept as aliases of `langid`.
 
-In this case, the `key!` macro would be useful for generating a `Key` instance from a literal string.
+The `key!` macro would be useful for generating a key from a literal string, e.g. `key!(language: "en")`, `key!(region: "US")`, `key!(language_region_pair: "en_US")` or `key!(langid: "zh-Hant-TW")`. `KeyParam<K>` parses a key of any type from a Rocket path parameter or form field.
+
+If a key does not exist, less specific keys are tried. A `LanguageIdentifier` key keeps the script subtag, so `zh-Hant` and `zh-Hans`, or `sr-Latn` and `sr-Cyrl`, can coexist, and `zh-Hant-HK` falls back to `zh-Hant` and then `zh`. A `(Language, Option<Region>)` key falls back from `en_US` to `en`.
 
 For example,
 
 ```toml
 [dependencies.json-gettext]
 version = "*"
-features = ["language_region_pair", "rocket"]
+features = ["langid", "rocket"]
 ```
 
 ```rust,ignore
@@ -90,31 +96,413 @@ extern crate json_gettext;
 
 use rocket::State;
 
-use rocket_accept_language::unic_langid::subtags::Language;
+use rocket_accept_language::unic_langid::subtags::{Language, Region};
 use rocket_accept_language::AcceptLanguage;
 
-use json_gettext::{JSONGetTextManager, Key};
+use json_gettext::JSONGetTextManager;
 
 const LANGUAGE_EN: Language = language!("en");
 
 #[get("/")]
-fn index(ctx: &State<JSONGetTextManager>, accept_language: &AcceptLanguage) -> String {
+fn index(ctx: &State<JSONGetTextManager<(Language, Option<Region>)>>, accept_language: &AcceptLanguage) -> String {
     let (language, region) = accept_language.get_first_language_region().unwrap_or((LANGUAGE_EN, None));
 
-    format!("Ron: {}", get_text!(ctx, Key(language, region), "hello").unwrap().as_str().unwrap())
+    let ctx = ctx.share();
+
+    format!("Ron: {}", get_text!(ctx, (language, region), "hello").unwrap().as_str().unwrap())
 }
 
 #[launch]
 fn rocket() -> _ {
     rocket::build()
         .attach(static_json_gettext_build_for_rocket!(
-            key!("en");
-            key!("en") => "langs/en_US.json",
-            key!("zh_TW") => "langs/zh_TW.json",
+            key!(language_region_pair: "en");
+            key!(language_region_pair: "en") => "langs/en_US.json",
+            key!(language_region_pair: "zh_TW") => "langs/zh_TW.json",
         ))
         .mount("/", routes![index])
 }
 ```
+
+## Android and Apple Resources
+
+The `android` feature and the `apple` feature can be enabled to convert between text maps and Android `strings.xml` resources, or Apple `.strings` / `.stringsdict` documents, so that mobile apps can share the same texts.
+
+`<string-array>` elements become JSON arrays, and `<plurals>` elements and plural `.stringsdict` entries become JSON objects whose keys are plural categories (`zero`, `one`, `two`, `few`, `many`, `other`). A plural object from a `.stringsdict` document also keeps `NSStringLocalizedFormatKey` and `NSStringFormatValueTypeKey` if its plural variable is not the default `value` variable of the `d` type, so the original format survives the conversion.
+
+```rust,ignore
+let mut builder = JSONGetText::build("en_US");
+
+builder.add_json("en_US", include_str!("langs/en_US.json")).unwrap();
+builder.add_android_strings_xml("zh_TW", include_str!("res/values-zh-rTW/strings.xml")).unwrap();
+
+let ctx = builder.build().unwrap();
+
+let strings_xml = ctx.to_android_strings_xml("en_US").unwrap();
+let strings = ctx.to_apple_strings("en_US").unwrap();
+let stringsdict = ctx.to_apple_stringsdict("en_US").unwrap();
+```
+
+## Flutter ARB and i18next
+
+Catalogs of other JSON dialects can be added without pre-processing.
+
+* `add_arb` reads Flutter ARB documents. The `@`-prefixed metadata entries are never added as texts. They are either stripped or retained as descriptions which can be read by `get_description` (`@hello` becomes the description of `hello`).
+* `add_i18next` reads i18next JSON documents. Nested objects are flattened with `.`, plural suffixes (`key_one` / `key_other`, or `key` / `key_plural`) are grouped into plural objects, and `$t(key)` nestings are resolved.
+
+```rust,ignore
+use json_gettext::{ARBMetadata, JSONGetText};
+
+let mut builder = JSONGetText::build("en");
+
+builder.add_arb("en", include_str!("l10n/app_en.arb"), ARBMetadata::Description).unwrap();
+builder.add_i18next("zh_TW", include_str!("locales/zh_TW/translation.json")).unwrap();
+
+let ctx = builder.build().unwrap();
+```
+
+## Binary Catalogs
+
+Parsing large JSON catalogs on every start can be slow. A built `JSONGetText` instance can be converted to a compact, versioned binary catalog with a checksum by `to_binary` or `write_binary`, and loaded again in one pass by `from_binary` or `from_binary_file`. Aliases, source paths and descriptions are kept in the catalog. `from_binary` borrows text values, including the strings in plural objects and lists, from the input bytes without copying them, so the bytes can be a `&'static [u8]` from `include_bytes!` or come from a memory-mapped file. `from_binary_file` returns a `JSONGetTextBinary` instance which owns the bytes of the file, and `into_owned` turns it into a standalone `JSONGetText<'static>` instance.
+
+```rust,ignore
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+).unwrap();
+
+std::fs::write("langs.bin", ctx.to_binary()).unwrap();
+
+let bytes = std::fs::read("langs.bin").unwrap();
+
+let ctx = JSONGetText::from_binary(&bytes).unwrap();
+```
+
+The `binary_catalog` example converts JSON files to a binary catalog.
+
+```bash
+cargo run --example binary_catalog -- langs.bin en_US en_US=langs/en_US.json zh_TW=langs/zh_TW.json
+```
+
+## Fallback to the Default Key
+
+Each key only stores the texts translated for it, and text ids are interned so they are allocated only once for all keys. `get` returns a `JSONGetTextMap` view which looks up the texts that are not translated from the default key, so it still behaves like a complete map. Use `is_translated` or `translated` to tell the translated texts apart.
+
+```rust,ignore
+let map = ctx.get("zh_TW");
+
+assert_eq!("Rust!", map.get("rust").unwrap());
+assert!(!map.is_translated("rust"));
+```
+
+## Compile-time Text Ids
+
+Enable the `macros` feature to check text ids at compile time. The `catalog!` macro reads a JSON file (relative to `CARGO_MANIFEST_DIR`) when compiling and generates a `TextId` enum, so a misspelled or removed text id becomes a compile error.
+
+```rust,ignore
+#[macro_use] extern crate json_gettext;
+
+json_gettext::catalog!("langs/en_US.json");
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json",
+)
+.unwrap();
+
+assert_eq!("Hello, world!", get_text!(ctx, TextId::Hello).unwrap());
+```
+
+`static_json_gettext_build!` returns a `JSONGetTextBuildError` if a JSON file cannot be parsed or a key is duplicated. With the feature enabled, it also verifies the JSON files during compilation, so malformed JSON files, duplicated keys and texts which do not exist in the file of the default key are reported as compile errors. Texts which are not translated are allowed, because they fall back to the default key. Put `#![strict]` in front of the default key to make every key translate every text of the default key.
+
+```rust,ignore
+let ctx = static_json_gettext_build!(
+    #![strict]
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json",
+)
+.unwrap();
+```
+
+## Message Functions
+
+With the `macros` feature, the `messages!` macro generates a function for every string in a JSON file. Every `{name}` placeholder becomes a parameter, so a missing argument is a compile error and IDEs can complete the functions.
+
+```rust,ignore
+#[macro_use] extern crate json_gettext;
+
+// "welcome": "Welcome, {name}!"
+json_gettext::messages!(msgs, "langs/en_US.json");
+
+let s: String = msgs::welcome(&ctx, "zh_TW", "Len");
+```
+
+Placeholders can also be replaced at runtime with `format_text`.
+
+## Reloadable Handle
+
+The `static_json_gettext_build_handle!` macro does not need any web framework. It returns a `JSONGetTextHandle`, which dereferences to `JSONGetText`.
+
+* In debug builds, the JSON files are read from the disk. Call `reload_if_needed` to reload them after they are modified.
+* In release builds, the JSON files are embedded into the executable. Enable the `embed` feature to embed them in debug builds too.
+
+```rust,ignore
+#[macro_use] extern crate json_gettext;
+
+let mut ctx = static_json_gettext_build_handle!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json",
+)
+.unwrap();
+
+ctx.reload_if_needed().unwrap();
+
+assert_eq!("Hello, world!", get_text!(ctx, "hello").unwrap());
+```
+
+## Key Normalization and Aliases
+
+`String` keys are normalized in the BCP 47 style when they are added and when they are looked up, so `en-us`, `en_us` and `en_US` are the same key. Subtags are separated by `_`, the language is lowercase, the script is titlecase and the region is uppercase. `get_keys` returns the normalized keys.
+
+Aliases can be added to the builder. A key which does not exist is looked up as its alias target.
+
+```rust,ignore
+let mut builder = JSONGetText::build("en_US");
+
+builder.add_json_file("en_US", "langs/en_US.json")?;
+builder.add_json_file("zh-Hans", "langs/zh_Hans.json")?;
+builder.add_alias("zh-CN", "zh_Hans");
+
+let ctx = builder.build()?;
+
+assert_eq!(ctx.get_text_with_key("zh-cn", "hello"), ctx.get_text_with_key("zh_Hans", "hello"));
+```
+
+## Lookup Provenance
+
+`lookup` returns a value together with where it comes from: the requested key, the key which is actually used after aliases and fallback keys are resolved, whether the text is copied from the default key because it is not translated, and the JSON file it is loaded from. `lookup_multiple` and `lookup_filtered` are the counterparts of `get_multiple_text_with_key` and `get_filtered_text_with_key`. This is useful for analytics and for highlighting untranslated texts.
+
+```rust,ignore
+let lookup = ctx.lookup("zh_TW", "rust").unwrap();
+
+assert_eq!("Rust!", lookup.value);
+assert_eq!("zh_TW", lookup.resolved_key);
+assert!(lookup.copied_from_default);
+assert_eq!(Some(Path::new("langs/en_US.json")), lookup.source);
+```
+
+## Missing Texts
+
+A `MissingTextHandler` can be set on a `JSONGetText` instance or its builder. It is called with the key, the text id and the call site whenever `get_text`, `get_text_with_key`, `get_multiple_text` or `get_multiple_text_with_key` cannot find a text, or returns the value of the default key because the text is not translated. A handler can also return a value to be used instead.
+
+* `MissingTextReport` collects missing texts in memory, and `to_json` exports them.
+* `PlaceholderMissingTextHandler` returns a placeholder such as `[[missing:hello]]`.
+* `LogMissingTextHandler` writes warnings by the `log` facade or the `tracing` facade. Enable the `log` feature or the `tracing` feature to use it.
+
+```rust,ignore
+let report = Arc::new(MissingTextReport::new());
+
+ctx.set_missing_text_handler(report.clone());
+
+get_text!(ctx, "zh_TW", "rust");
+
+println!("{}", report.to_json());
+```
+
+## Pseudo-localization
+
+A pseudo-locale can be generated from the texts of the default key to catch hard-coded strings and truncation before real translations are available. `PseudoLocaleOptions` controls accented characters, length expansion by a percentage, bracket markers and right-to-left mirroring. Placeholders, printf-style formats, HTML tags and HTML entities are kept intact.
+
+```rust,ignore
+let mut builder = JSONGetText::build("en_US");
+
+builder.add_json_file("en_US", "langs/en_US.json")?;
+builder.add_pseudo_locale("en_XA", &PseudoLocaleOptions::accented())?;
+builder.add_pseudo_locale("ar_XB", &PseudoLocaleOptions::mirrored())?;
+
+let ctx = builder.build()?;
+
+assert_eq!("[Ḥḗŀŀǿ, ẇǿřŀḓ!~~~~]", ctx.get_text_with_key("en_XA", "hello").unwrap());
+```
+
+## JSON Escaping
+
+`to_json_string` escapes strings as RFC 8259 requires, so the output (including the response of the Rocket `Responder` implementation) is always valid JSON. `JSONEscapeOptions` can additionally escape `/`, `<`, `>` and `&`, and the line separators U+2028 and U+2029 for embedding JSON in a `<script>` element. `write_json` writes a value to any `io::Write` without building a string first.
+
+```rust,ignore
+let value = ctx.get_text("hello").unwrap();
+
+let json = value.to_json_string_with_options(&JSONEscapeOptions::html());
+
+value.write_json(std::io::stdout(), &JSONEscapeOptions::default())?;
+```
+
+## Typed Values
+
+Besides `as_str`, `JSONGetTextValue` has typed accessors (`as_i64`, `as_u64`, `as_f64`, `as_bool`, `as_array`, `as_object` and `is_null`) and can be indexed by a key or a position with `get` or `[]`. `deserialize_into` maps a structured value straight into your own type.
+
+```rust,ignore
+let days: Vec<String> = ctx.get_text("weekdays").unwrap().deserialize_into()?;
+
+let first_day = ctx.get_text("weekdays").unwrap().get(0).unwrap().to_string();
+```
+
+`as_array`, `as_object` and `[]` work on values stored as a `serde_json::Value`, e.g. those created by `parse_json`. `get`, `iter_array` and `iter_object` also work on `JSONTree` values (see below) and return borrowed `JSONGetTextValue` instances. A `JSONTree` itself, returned by `as_tree`, can be indexed by `[]` as well.
+
+```rust,ignore
+for (category, text) in ctx.get_text("apples").unwrap().iter_object().unwrap() {
+    println!("{category}: {text}");
+}
+```
+
+## Borrowed JSON Trees
+
+Objects and arrays are loaded as `JSONTree` values instead of `serde_json::Value`. A `JSONTree` borrows its strings from the JSON source, so nested structures such as plural objects in an embedded catalog (`add_json`, which `static_json_gettext_build!` uses in release builds or with the `embed` feature) are zero-copy. `add_json_owned` and `add_json_file` read the JSON into one shared buffer which all of its strings point to, instead of allocating every string separately.
+
+```rust,ignore
+let apples = ctx.get_text("apples").unwrap();
+
+assert!(matches!(apples, JSONGetTextValue::TreeRef(_)));
+assert_eq!("# apple", apples.get("one").unwrap());
+```
+
+## Owned Values
+
+`JSONGetTextValue::Str` holds a `Cow<str>`, so borrowed texts and formatted (owned) texts share one variant. `into_owned` turns any value into a `JSONGetTextValue<'static>` which can be moved into a spawned task or a cache. Values implement `Eq`, `Ord` and `Hash` by their JSON content, regardless of how they are stored, so they can be used as map keys.
+
+```rust,ignore
+let hello = ctx.get_text("hello").unwrap().into_owned();
+
+std::thread::spawn(move || println!("{}", hello));
+```
+
+## HTML and Markdown
+
+Translations often contain inline markup like `<b>{name}</b>`. `render_html` (or `format_html`) treats the text in the catalog as trusted markup and escapes only the interpolated arguments, returning an `HTMLText`. Enable the `markdown` feature to render texts whose IDs end with `.md` or `_md` from Markdown (`format_markdown`); the arguments are escaped so that they are always rendered as plain text. With the `rocket` feature, `HTMLText` can be returned from a handler and is responded with the `text/html` content type.
+
+```rust,ignore
+// "welcome": "Welcome, <b>{name}</b>!"
+let html = ctx.render_html("welcome", &[("name", &user_name)]).unwrap();
+
+// "intro.md": "Read the **{title}** guide."
+let html = ctx.render_html_with_key("zh_TW", "intro.md", &[("title", &title)]).unwrap();
+```
+
+## Bundles for Rocket
+
+`JSONGetTextManager::bundle_routes` creates a ready-made route set which responds all the texts of a key, or the ones whose IDs match a regex, as one JSON object. Responses have a strong ETag derived from the catalog generation (which increases when the JSON files are reloaded in debug builds) and the content, `Cache-Control` (`no-cache` by default, see `bundle_routes_with_cache_control`) and `Vary: Accept-Language`, and `304 Not Modified` is responded if the `If-None-Match` header matches.
+
+```rust,ignore
+rocket::build()
+    .attach(static_json_gettext_build_for_rocket!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    ))
+    // GET /i18n/zh_TW, GET /i18n/zh_TW?filter=^home\.
+    .mount("/i18n", JSONGetTextManager::<String>::bundle_routes())
+```
+
+A `JSONGetTextBundle` created by `JSONGetTextManager::bundle` can also be returned from your own handlers.
+
+## Locale Request Guard for Rocket
+
+The `Locale` request guard resolves the locale of a request from a path segment, a query parameter, a cookie and the `Accept-Language` header, in the order given by a managed `LocaleConfig` (the `lang` query parameter, the `lang` cookie and then `Accept-Language` by default). A candidate is used only if the `JSONGetTextManager` state has texts for it, otherwise the default key is used. `LocaleConfig::set_cookie` remembers the language chosen by the user.
+
+```rust,ignore
+#[get("/<_lang>/hello")]
+fn hello(_lang: &str, ctx: &State<JSONGetTextManager>, locale: Locale) -> String {
+    ctx.get_text_with_key(locale.as_str(), "hello").unwrap().to_string()
+}
+
+#[get("/switch?<lang>")]
+fn switch(lang: &str, config: &State<LocaleConfig>, cookies: &CookieJar<'_>) {
+    config.set_cookie(cookies, &lang.to_string());
+}
+
+rocket::build()
+    .attach(static_json_gettext_build_for_rocket!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    ))
+    .manage(LocaleConfig::new(vec![LocaleSource::PathSegment(0), LocaleSource::Cookie, LocaleSource::AcceptLanguage]))
+    .mount("/", routes![hello, switch])
+```
+
+## Templates for Rocket
+
+The `tera` feature and the `handlebars` feature add the `t` and `t_plural` template functions (Tera) / helpers (Handlebars) for `rocket_dyn_templates`, which is re-exported. Attach `JSONGetTextManager::templates_fairing` (or `templates_fairing_with` to customize the engines) after the fairing of `JSONGetTextManager`, instead of `Template::fairing`. The functions look up texts from the managed `JSONGetTextManager`, so reloaded JSON files take effect immediately in debug builds.
+
+`t_plural` chooses the form of a plural text for a count by the CLDR plural rules of the locale, e.g. `one`, `few`, `many` or `other` in Russian, and falls back to `other`. The count is also given as the `count` argument. Put the `Locale` of a request into the context as `lang`. Handlebars helpers read it from the context, and Tera functions take it as an argument.
+
+```rust,ignore
+use json_gettext::rocket_dyn_templates::{context, Template};
+
+#[get("/")]
+fn index(locale: Locale) -> Template {
+    // index.html.tera: {{ t(text="hello", lang=lang, name=name) }} {{ t_plural(text="apples", count=3, lang=lang) }}
+    // index.html.hbs: {{t "hello" name=name}} {{t_plural "apples" 3}}
+    Template::render("index", context! { lang: locale, name: "Len" })
+}
+
+rocket::build()
+    .attach(static_json_gettext_build_for_rocket!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    ))
+    .attach(JSONGetTextManager::<String>::templates_fairing())
+    .mount("/", routes![index])
+```
+
+## Axum Support
+
+The `axum` feature adds `JSONGetTextLayer`, a Tower layer which negotiates the locale of every request from the sources in a `LocaleConfig` (a path segment, a query parameter, a cookie and the `Accept-Language` header, validated against the keys of the catalog), and a `Translator` extractor bound to the locale over a shared `JSONGetText`. `JSONGetTextValue` and `HTMLText` implement `IntoResponse`.
+
+```rust,ignore
+use std::sync::Arc;
+
+use axum::{routing::get, Router};
+use json_gettext::{JSONGetTextLayer, Translator};
+
+async fn hello(translator: Translator) -> String {
+    translator.t_with("hello", &[("name", &"Len")]).unwrap()
+}
+
+let ctx = Arc::new(static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+).unwrap());
+
+let app: Router = Router::new().route("/hello", get(hello)).layer(JSONGetTextLayer::new(ctx));
+```
+
+## Actix Web Support
+
+The `actix` feature adds `JSONGetTextData`, which shares a `JSONGetTextHandle` with the workers of an actix-web server and reloads modified json files in debug builds. Register it as app data, and then the `Translator` extractor negotiates the locale of every request from the sources in a `LocaleConfig`, like the axum support does. `JSONGetTextValue` and `HTMLText` implement `Responder`.
+
+```rust,ignore
+use actix_web::{web, App, HttpServer};
+use json_gettext::{JSONGetTextData, Translator};
+
+async fn hello(translator: Translator) -> String {
+    translator.t_with("hello", &[("name", &"Len")]).unwrap()
+}
+
+let data = web::Data::new(JSONGetTextData::new(static_json_gettext_build_handle!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json",
+).unwrap()));
+
+HttpServer::new(move || App::new().app_data(data.clone()).route("/hello", web::get().to(hello)))
+```
 */
 
 pub extern crate serde_json;
@@ -122,31 +510,68 @@ pub extern crate serde_json;
 #[cfg(feature = "langid")]
 pub extern crate unic_langid;
 
+#[cfg(any(feature = "tera", feature = "handlebars"))]
+pub extern crate rocket_dyn_templates;
+
 #[doc(hidden)]
 pub extern crate manifest_dir_macros;
 
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub extern crate json_gettext_macros;
+
+mod converters;
+mod format;
+mod handle;
+mod html;
+mod interned_context;
+mod json_get_text_binary;
 mod json_get_text_build_errors;
+mod json_get_text_builder;
+mod json_get_text_lookup;
+mod json_get_text_map;
+mod json_gettext;
+mod locale_key;
+#[cfg(any(feature = "rocket", feature = "axum", feature = "actix"))]
+mod locale_negotiation;
 mod macros;
+mod missing_text;
+mod pseudo_locale;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod translator;
 mod value;
 
-#[cfg(all(debug_assertions, feature = "rocket"))]
-mod mutate;
-
-#[cfg(feature = "langid")]
-mod key_copy;
-
-#[cfg(not(feature = "langid"))]
-mod key_string;
-
+#[cfg(feature = "actix")]
+mod actix_feature;
+#[cfg(feature = "axum")]
+mod axum_feature;
+#[cfg(feature = "rocket")]
+mod rocket_feature;
+
+#[cfg(feature = "actix")]
+pub use actix_feature::*;
+#[cfg(feature = "axum")]
+pub use axum_feature::*;
+pub use converters::*;
+pub use format::*;
+pub use handle::*;
+pub use html::*;
+pub use json_get_text_binary::*;
 pub use json_get_text_build_errors::*;
-#[cfg(feature = "langid")]
-pub use key_copy::*;
-#[cfg(not(feature = "langid"))]
-pub use key_string::*;
-#[cfg(all(debug_assertions, feature = "rocket"))]
-use mutate::DebuggableMutate;
-#[cfg(any(feature = "language", feature = "region"))]
-pub use unic_langid::parser::ParserError;
-#[cfg(feature = "language_region_pair")]
-pub use unic_langid::LanguageIdentifierError;
+pub use json_get_text_builder::*;
+pub use json_get_text_lookup::*;
+pub use json_get_text_map::*;
+#[cfg(feature = "macros")]
+pub use json_gettext_macros::{catalog, messages};
+pub use locale_key::*;
+#[cfg(any(feature = "rocket", feature = "axum", feature = "actix"))]
+pub use locale_negotiation::*;
+pub use missing_text::*;
+pub use pseudo_locale::*;
+#[cfg(feature = "rocket")]
+pub use rocket_feature::*;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use translator::*;
 pub use value::*;
+
+pub use self::json_gettext::*;
@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Formatter, Write},
+    str::FromStr,
+};
+
+use super::{AsLocaleKey, IntoLocaleKey, LocaleKey};
+use crate::unic_langid::{
+    subtags::{Language, Region},
+    LanguageIdentifier,
+};
+
+/// Get the value of a key or of its fallback keys from a map.
+#[inline]
+fn get_fallback<'m, K: LocaleKey, V>(key: &K, map: &'m HashMap<K, V>) -> Option<&'m V> {
+    if let Some(value) = map.get(key) {
+        return Some(value);
+    }
+
+    let mut key = key.fallback()?;
+
+    loop {
+        if let Some(value) = map.get(&key) {
+            return Some(value);
+        }
+
+        key = key.fallback()?;
+    }
+}
+
+macro_rules! impl_key_traits {
+    ($t:ty) => {
+        impl IntoLocaleKey for &$t {
+            type Key = $t;
+
+            #[inline]
+            fn into_locale_key(self) -> $t {
+                self.clone()
+            }
+        }
+
+        impl AsLocaleKey<$t> for $t {
+            #[inline]
+            fn get_from<'m, V>(&self, map: &'m HashMap<$t, V>) -> Option<&'m V> {
+                map.get(self)
+            }
+
+            #[inline]
+            fn get_fallback_from<'m, V>(&self, map: &'m HashMap<$t, V>) -> Option<&'m V> {
+                get_fallback(self, map)
+            }
+
+            #[inline]
+            fn fmt_as_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                LocaleKey::fmt_key(self, f)
+            }
+
+            #[inline]
+            fn to_locale_key(&self) -> Option<$t> {
+                Some(self.clone().normalize())
+            }
+        }
+
+        impl AsLocaleKey<$t> for &$t {
+            #[inline]
+            fn get_from<'m, V>(&self, map: &'m HashMap<$t, V>) -> Option<&'m V> {
+                map.get(*self)
+            }
+
+            #[inline]
+            fn get_fallback_from<'m, V>(&self, map: &'m HashMap<$t, V>) -> Option<&'m V> {
+                get_fallback(*self, map)
+            }
+
+            #[inline]
+            fn fmt_as_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                LocaleKey::fmt_key(*self, f)
+            }
+
+            #[inline]
+            fn to_locale_key(&self) -> Option<$t> {
+                Some((*self).clone().normalize())
+            }
+        }
+    };
+}
+
+impl LocaleKey for Language {
+    #[inline]
+    fn parse_key(s: &str) -> Option<Self> {
+        Language::from_str(s).ok()
+    }
+
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl LocaleKey for Region {
+    #[inline]
+    fn parse_key(s: &str) -> Option<Self> {
+        Region::from_str(s).ok()
+    }
+
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The language and the region of a language identifier. It is formatted like `en_US`.
+impl LocaleKey for (Language, Option<Region>) {
+    #[inline]
+    fn parse_key(s: &str) -> Option<Self> {
+        let langid = LanguageIdentifier::from_str(s).ok()?;
+
+        Some((langid.language, langid.region))
+    }
+
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.as_str())?;
+
+        if let Some(region) = self.1 {
+            f.write_char('_')?;
+            f.write_str(region.as_str())?;
+        }
+
+        Ok(())
+    }
+
+    /// `en_US` falls back to `en`.
+    #[inline]
+    fn fallback(&self) -> Option<Self> {
+        self.1.map(|_| (self.0, None))
+    }
+}
+
+/// A full language identifier. It is formatted like `zh-Hant-TW`.
+impl LocaleKey for LanguageIdentifier {
+    #[inline]
+    fn parse_key(s: &str) -> Option<Self> {
+        LanguageIdentifier::from_str(s).ok()
+    }
+
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    /// Variants are removed first, then the region and then the script, e.g. `zh-Hant-HK` falls back to `zh-Hant` and then `zh`.
+    fn fallback(&self) -> Option<Self> {
+        let mut langid = self.clone();
+
+        if langid.variants().len() > 0 {
+            langid.clear_variants();
+        } else if langid.region.is_some() {
+            langid.region = None;
+        } else if langid.script.is_some() {
+            langid.script = None;
+        } else {
+            return None;
+        }
+
+        Some(langid)
+    }
+}
+
+impl_key_traits!(Language);
+impl_key_traits!(Region);
+impl_key_traits!((Language, Option<Region>));
+impl_key_traits!(LanguageIdentifier);
@@ -0,0 +1,204 @@
+#[cfg(feature = "langid")]
+mod langid;
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// Types which can be used as **keys** (usually considered as locales) of a `JSONGetText` instance.
+///
+/// It is implemented for `String`, and with the `langid` feature, for `Language`, `Region`, `(Language, Option<Region>)` and `LanguageIdentifier`. Different crates can use different key types at the same time.
+pub trait LocaleKey: Debug + Clone + Eq + Hash + Send + Sync + 'static {
+    /// Parse a key from a string. `None` is returned if the string is not a valid key.
+    fn parse_key(s: &str) -> Option<Self>;
+
+    /// Format the key as a string which can be parsed by `parse_key`.
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Convert the key to a string which can be parsed by `parse_key`.
+    #[inline]
+    fn to_key_string(&self) -> String {
+        DisplayKey(self).to_string()
+    }
+
+    /// Convert the key to its canonical form. Keys are normalized when they are added to a builder, so that different spellings of a key become the same key.
+    #[inline]
+    fn normalize(self) -> Self {
+        self
+    }
+
+    /// Get a less specific key which is tried when this key does not exist, e.g. `zh-Hant` for `zh-Hant-HK`. `None` means there is no fallback key.
+    #[inline]
+    fn fallback(&self) -> Option<Self> {
+        None
+    }
+}
+
+/// A wrapper which implements `Display` for a `LocaleKey`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayKey<'a, K: LocaleKey>(pub &'a K);
+
+impl<'a, K: LocaleKey> Display for DisplayKey<'a, K> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_key(f)
+    }
+}
+
+/// Types which can be converted into a key of a `JSONGetText` instance. The key type is decided by the type being converted, so `"en_US"` becomes a `String` key.
+pub trait IntoLocaleKey {
+    type Key: LocaleKey;
+
+    fn into_locale_key(self) -> Self::Key;
+}
+
+/// Types which can be used to look up a key of type `K` without creating a `K` instance, e.g. `&str` for `String` keys.
+pub trait AsLocaleKey<K: LocaleKey> {
+    /// Get the value of this key from a map.
+    fn get_from<'m, V>(&self, map: &'m HashMap<K, V>) -> Option<&'m V>;
+
+    /// Format this key as it is requested.
+    fn fmt_as_key(&self, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Get the value of this key from a map. If this key does not exist, its fallback keys are tried in order.
+    #[inline]
+    fn get_fallback_from<'m, V>(&self, map: &'m HashMap<K, V>) -> Option<&'m V> {
+        self.get_from(map)
+    }
+
+    /// Create the normalized `K` instance of this key, e.g. for [`JSONGetText::lookup`](crate::JSONGetText::lookup), which returns the requested key. `None` is returned if it is not a valid key. By default, the formatted key is parsed.
+    #[inline]
+    fn to_locale_key(&self) -> Option<K> {
+        K::parse_key(&RequestedKey::<K, Self>(self, PhantomData).to_string()).map(K::normalize)
+    }
+}
+
+/// Display a requested key which may not be a `K` instance.
+pub(crate) struct RequestedKey<'q, K, Q: ?Sized>(pub(crate) &'q Q, pub(crate) PhantomData<K>);
+
+impl<'q, K: LocaleKey, Q: AsLocaleKey<K> + ?Sized> Display for RequestedKey<'q, K, Q> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_as_key(f)
+    }
+}
+
+/// String keys are normalized in the BCP 47 style. Subtags are separated by `_`, the language is lowercase, the script is titlecase and the region is uppercase, e.g. `en-us` becomes `en_US` and `ZH-hant-tw` becomes `zh_Hant_TW`. Subtags after a singleton (e.g. `x`) are lowercase.
+impl LocaleKey for String {
+    #[inline]
+    fn parse_key(s: &str) -> Option<Self> {
+        Some(normalize_str_key(s).into_owned())
+    }
+
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+
+    #[inline]
+    fn to_key_string(&self) -> String {
+        self.clone()
+    }
+
+    #[inline]
+    fn normalize(self) -> Self {
+        match normalize_str_key(&self) {
+            Cow::Borrowed(_) => self,
+            Cow::Owned(key) => key,
+        }
+    }
+}
+
+impl<K: LocaleKey> IntoLocaleKey for K {
+    type Key = K;
+
+    #[inline]
+    fn into_locale_key(self) -> K {
+        self
+    }
+}
+
+impl IntoLocaleKey for &str {
+    type Key = String;
+
+    #[inline]
+    fn into_locale_key(self) -> String {
+        String::from(self)
+    }
+}
+
+impl IntoLocaleKey for &String {
+    type Key = String;
+
+    #[inline]
+    fn into_locale_key(self) -> String {
+        self.clone()
+    }
+}
+
+/// String keys which are not in the canonical form are normalized on lookup, so `en-us` finds `en_US`.
+impl<T: AsRef<str> + ?Sized> AsLocaleKey<String> for T {
+    #[inline]
+    fn get_from<'m, V>(&self, map: &'m HashMap<String, V>) -> Option<&'m V> {
+        let key = self.as_ref();
+
+        match map.get(key) {
+            Some(value) => Some(value),
+            None => match normalize_str_key(key) {
+                Cow::Borrowed(_) => None,
+                Cow::Owned(key) => map.get(&key),
+            },
+        }
+    }
+
+    #[inline]
+    fn fmt_as_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+
+    #[inline]
+    fn to_locale_key(&self) -> Option<String> {
+        Some(normalize_str_key(self.as_ref()).into_owned())
+    }
+}
+
+/// `normalize_key` in `json-gettext-macros` has to normalize keys in the same way, so that they can be verified at compile time.
+fn normalize_str_key(key: &str) -> Cow<'_, str> {
+    let mut normalized = String::with_capacity(key.len());
+    let mut extension = false;
+
+    for (i, subtag) in key.split(['-', '_']).enumerate() {
+        if i > 0 {
+            normalized.push('_');
+        }
+
+        let is_alphabetic = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+
+        if i == 0 || extension || subtag.len() == 1 {
+            extension = extension || (i > 0 && subtag.len() == 1);
+
+            normalized.push_str(&subtag.to_ascii_lowercase());
+        } else if subtag.len() == 4 && is_alphabetic {
+            let (first, rest) = subtag.split_at(1);
+
+            normalized.push_str(&first.to_ascii_uppercase());
+            normalized.push_str(&rest.to_ascii_lowercase());
+        } else if (subtag.len() == 2 && is_alphabetic)
+            || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+        {
+            normalized.push_str(&subtag.to_ascii_uppercase());
+        } else {
+            normalized.push_str(&subtag.to_ascii_lowercase());
+        }
+    }
+
+    if normalized == key {
+        Cow::Borrowed(key)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
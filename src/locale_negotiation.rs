@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use crate::{JSONGetText, LocaleKey};
+
+/// The default name of the cookie which remembers the locale chosen by the user.
+pub const DEFAULT_LOCALE_COOKIE_NAME: &str = "lang";
+
+/// A place where the locale of a request is looked for.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum LocaleSource {
+    /// A segment of the path, e.g. `0` for `/<lang>/...`. For Rocket, segments are counted from the base where the route is mounted.
+    PathSegment(usize),
+    /// A query parameter of the name, e.g. `lang` for `?lang=zh_TW`.
+    Query(Cow<'static, str>),
+    /// The cookie named by [`LocaleConfig::get_cookie_name`].
+    Cookie,
+    /// The `Accept-Language` header. Languages are tried in the order of their quality values.
+    AcceptLanguage,
+}
+
+/// The configuration of locale negotiation. The default one looks for the `lang` query parameter, the `lang` cookie and then the `Accept-Language` header.
+#[derive(Debug, Clone)]
+pub struct LocaleConfig {
+    sources:     Vec<LocaleSource>,
+    cookie_name: Cow<'static, str>,
+}
+
+impl LocaleConfig {
+    /// Create a `LocaleConfig` instance with sources in the order of precedence.
+    #[inline]
+    pub fn new(sources: Vec<LocaleSource>) -> LocaleConfig {
+        LocaleConfig {
+            sources,
+            cookie_name: Cow::Borrowed(DEFAULT_LOCALE_COOKIE_NAME),
+        }
+    }
+
+    /// Set the name of the cookie. It is [`DEFAULT_LOCALE_COOKIE_NAME`] by default.
+    #[inline]
+    pub fn with_cookie_name<S: Into<Cow<'static, str>>>(mut self, cookie_name: S) -> Self {
+        self.cookie_name = cookie_name.into();
+
+        self
+    }
+
+    #[inline]
+    pub fn get_sources(&self) -> &[LocaleSource] {
+        &self.sources
+    }
+
+    #[inline]
+    pub fn get_cookie_name(&self) -> &str {
+        &self.cookie_name
+    }
+
+    /// Find the key of a request from the sources. A candidate is used only if `ctx` has texts for it (aliases and fallback keys are resolved), and the key which has the texts is returned with the source. The default key is returned without a source if no candidate is usable.
+    pub(crate) fn negotiate<K: LocaleKey, R: LocaleRequest + ?Sized>(
+        &self,
+        ctx: &JSONGetText<K>,
+        req: &R,
+    ) -> (K, Option<&LocaleSource>) {
+        let resolve = |s: &str| {
+            K::parse_key(s.trim()).and_then(|key| ctx.resolve_key(&key.normalize()).cloned())
+        };
+
+        for source in self.sources.iter() {
+            let key = match source {
+                LocaleSource::PathSegment(index) => {
+                    req.path_segment(*index).and_then(|s| resolve(&s))
+                },
+                LocaleSource::Query(name) => req.query_value(name).and_then(|s| resolve(&s)),
+                LocaleSource::Cookie => req.cookie(&self.cookie_name).and_then(|s| resolve(&s)),
+                LocaleSource::AcceptLanguage => req
+                    .accept_language()
+                    .and_then(|value| parse_accept_language(&value).into_iter().find_map(resolve)),
+            };
+
+            if let Some(key) = key {
+                return (key, Some(source));
+            }
+        }
+
+        (ctx.get_default_key().clone(), None)
+    }
+}
+
+impl Default for LocaleConfig {
+    #[inline]
+    fn default() -> Self {
+        LocaleConfig::new(vec![
+            LocaleSource::Query(Cow::Borrowed(DEFAULT_LOCALE_COOKIE_NAME)),
+            LocaleSource::Cookie,
+            LocaleSource::AcceptLanguage,
+        ])
+    }
+}
+
+/// The parts of a request of a web framework which locales are negotiated from.
+pub(crate) trait LocaleRequest {
+    fn path_segment(&self, index: usize) -> Option<Cow<'_, str>>;
+
+    fn query_value(&self, name: &str) -> Option<Cow<'_, str>>;
+
+    fn cookie(&self, name: &str) -> Option<Cow<'_, str>>;
+
+    fn accept_language(&self) -> Option<Cow<'_, str>>;
+}
+
+/// Find the value of a cookie in `Cookie` header values.
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub(crate) fn find_cookie<'h, I: IntoIterator<Item = &'h str>>(
+    cookie_headers: I,
+    name: &str,
+) -> Option<&'h str> {
+    cookie_headers
+        .into_iter()
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(n, _)| *n == name)
+        .map(|(_, value)| value.trim_matches('"'))
+}
+
+/// Get the n-th non-empty segment of a path.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[inline]
+pub(crate) fn path_segment(path: &str, index: usize) -> Option<&str> {
+    path.split('/').filter(|s| !s.is_empty()).nth(index)
+}
+
+/// Get the value of a parameter of a query string.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[inline]
+pub(crate) fn query_value<'q>(query: &'q str, name: &str) -> Option<Cow<'q, str>> {
+    form_urlencoded::parse(query.as_bytes()).find(|(n, _)| n == name).map(|(_, value)| value)
+}
+
+/// Get the language tags of an `Accept-Language` header value in the order of their quality values. Tags whose quality values are `0` and the wildcard are excluded.
+fn parse_accept_language(value: &str) -> Vec<&str> {
+    let mut tags: Vec<(&str, f32)> = value
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+
+            let tag = parts.next()?.trim();
+
+            let q = parts
+                .find_map(|part| part.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse().unwrap_or(0.0))
+                .unwrap_or(1.0);
+
+            if tag.is_empty() || tag == "*" || q <= 0.0 {
+                None
+            } else {
+                Some((tag, q))
+            }
+        })
+        .collect();
+
+    tags.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
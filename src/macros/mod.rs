@@ -1,8 +1,28 @@
 #[cfg(feature = "rocket")]
 mod rocket_feature;
 
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! verify_static_json_files {
+    ($($t:tt)*) => {};
+}
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! verify_static_json_files {
+    ( $($t:tt)* ) => {
+        $crate::json_gettext_macros::verify_catalogs!($($t)*);
+    };
+}
+
 /**
-Used for including json files into your executable binary file for building a `JSONGetText` instance.
+Used for including json files into your executable binary file for building a `JSONGetText` instance. Errors are returned instead of panicking.
+
+With the `macros` feature, the json files are also verified at compile time. Malformed json files, duplicated keys and texts which do not exist in the json file of the default key become compile errors.
+
+Put `#![strict]` in front of the default key to also make texts which are not translated by a key compile errors. Paths and the default key must be string literals (or `key!` macros taking string literals) to be verified.
 
 ```ignore
 #[macro_use] extern crate json_gettext;
@@ -21,17 +41,133 @@ println!("{:?}", ctx);
 **/
 #[macro_export]
 macro_rules! static_json_gettext_build {
+    ( #![$attr:ident] $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build!(@[$attr] $default_key; $($key => $path), *)
+    };
+    ( @[$($attr:ident)?] $default_key:expr; $($key:expr => $path:expr), * ) => {
+        {
+            $crate::verify_static_json_files!($(#![$attr])? $default_key; $($key => $path), *);
+
+            (|| -> ::core::result::Result<$crate::JSONGetText<'static, _>, $crate::JSONGetTextBuildError> {
+                let mut builder = $crate::JSONGetText::build($default_key);
+
+                $(
+                    builder.add_json($key, include_str!($crate::manifest_dir_macros::path!($path)))?;
+                )*
+
+                builder.build()
+            })()
+        }
+    };
     ( $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build!(@[] $default_key; $($key => $path), *)
+    };
+}
+
+/**
+Used for building a `JSONGetTextHandle` instance from json files without any web framework. Errors are returned instead of panicking.
+
+In debug builds, the json files are read from the disk and can be reloaded by `reload_if_needed` when they are modified. In release builds, or if the `embed` feature is enabled, the json files are embedded into the executable binary file.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let mut ctx = static_json_gettext_build_handle!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json",
+)
+.unwrap();
+
+ctx.reload_if_needed().unwrap();
+
+assert_eq!("Hello, world!", get_text!(ctx, "hello").unwrap());
+```
+**/
+#[cfg(all(debug_assertions, not(feature = "embed")))]
+#[macro_export]
+macro_rules! static_json_gettext_build_handle {
+    ( #![$attr:ident] $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build_handle!(@[$attr] $default_key; $($key => $path), *)
+    };
+    ( @[$($attr:ident)?] $default_key:expr; $($key:expr => $path:expr), * ) => {
         {
-            let mut builder = $crate::JSONGetText::build($default_key);
+            $crate::verify_static_json_files!($(#![$attr])? $default_key; $($key => $path), *);
 
-            $(
-                builder.add_json($key, include_str!($crate::manifest_dir_macros::path!($path))).unwrap();
-            )*
+            $crate::JSONGetTextHandle::from_files(
+                $default_key,
+                vec![$(($key, $crate::manifest_dir_macros::not_directory_path!($path))), *],
+            )
+        }
+    };
+    ( $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build_handle!(@[] $default_key; $($key => $path), *)
+    };
+}
+
+/**
+Used for building a `JSONGetTextHandle` instance from json files without any web framework. Errors are returned instead of panicking.
 
-            builder.build()
+In debug builds, the json files are read from the disk and can be reloaded by `reload_if_needed` when they are modified. In release builds, or if the `embed` feature is enabled, the json files are embedded into the executable binary file.
+**/
+#[cfg(any(not(debug_assertions), feature = "embed"))]
+#[macro_export]
+macro_rules! static_json_gettext_build_handle {
+    ( #![$attr:ident] $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build_handle!(@[$attr] $default_key; $($key => $path), *)
+    };
+    ( @[$($attr:ident)?] $default_key:expr; $($key:expr => $path:expr), * ) => {
+        {
+            $crate::verify_static_json_files!($(#![$attr])? $default_key; $($key => $path), *);
+
+            $crate::JSONGetTextHandle::from_jsons(
+                $default_key,
+                vec![$(($key, include_str!($crate::manifest_dir_macros::path!($path)))), *],
+            )
         }
     };
+    ( $default_key:expr; $($key:expr => $path:expr), * $(,)* ) => {
+        $crate::static_json_gettext_build_handle!(@[] $default_key; $($key => $path), *)
+    };
+}
+
+/**
+Create a literal key. A string literal creates a `String` key. With the `langid` feature, the type of the key can be chosen by a prefix and the key is validated at compile time.
+
+```rust
+#[macro_use] extern crate json_gettext;
+
+let key = key!("en_US");
+
+assert_eq!(String::from("en_US"), key);
+```
+
+```ignore
+let language = key!(language: "en");
+let region = key!(region: "US");
+let language_region_pair = key!(language_region_pair: "en_US");
+let langid = key!(langid: "zh-Hant-TW");
+```
+*/
+#[macro_export]
+macro_rules! key {
+    (language: $key:expr) => {
+        $crate::unic_langid::lang!($key)
+    };
+    (region: $key:expr) => {
+        $crate::unic_langid::region!($key)
+    };
+    (language_region_pair: $key:expr) => {{
+        let langid = $crate::unic_langid::langid!($key);
+
+        (langid.language, langid.region)
+    }};
+    (langid: $key:expr) => {
+        $crate::unic_langid::langid!($key)
+    };
+    ($key:expr) => {
+        format!($key)
+    };
 }
 
 /**
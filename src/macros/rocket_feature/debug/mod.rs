@@ -1,14 +1,22 @@
 #[macro_export]
 macro_rules! static_json_gettext_build_for_rocket {
-    ( $default_key:expr; $( $key:expr => $path:expr ), * $(,)* ) => {
+    ( #![$attr:ident] $default_key:expr; $( $key:expr => $path:expr ), * $(,)* ) => {
+        $crate::static_json_gettext_build_for_rocket!(@[$attr] $default_key; $($key => $path), *)
+    };
+    ( @[$($attr:ident)?] $default_key:expr; $( $key:expr => $path:expr ), * ) => {
         $crate::JSONGetTextManager::fairing(|| {
+            $crate::verify_static_json_files!($(#![$attr])? $default_key; $($key => $path), *);
+
             let mut v = Vec::new();
 
             $(
-                v.push(($key, $crate::manifest_dir_macros::not_directory_path!($path)));
+                v.push(($crate::IntoLocaleKey::into_locale_key($key), $crate::manifest_dir_macros::not_directory_path!($path)));
             )*
 
-            ($default_key ,v)
+            ($crate::IntoLocaleKey::into_locale_key($default_key), v)
         })
     };
+    ( $default_key:expr; $( $key:expr => $path:expr ), * $(,)* ) => {
+        $crate::static_json_gettext_build_for_rocket!(@[] $default_key; $($key => $path), *)
+    };
 }
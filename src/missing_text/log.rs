@@ -0,0 +1,30 @@
+use super::{MissingText, MissingTextHandler};
+use crate::JSONGetTextValue;
+
+/// A `MissingTextHandler` which writes a warning for every missing text by the `log` facade, or the `tracing` facade, depending on which feature is enabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogMissingTextHandler;
+
+impl MissingTextHandler for LogMissingTextHandler {
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>> {
+        #[cfg(feature = "log")]
+        log::warn!(
+            "The text `{}` is {} for the key `{}` at {}.",
+            missing.text,
+            missing.kind,
+            missing.key,
+            missing.location
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            key = missing.key,
+            text = missing.text,
+            kind = missing.kind.as_str(),
+            location = %missing.location,
+            "missing text"
+        );
+
+        None
+    }
+}
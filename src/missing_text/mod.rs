@@ -0,0 +1,75 @@
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod log;
+mod placeholder;
+mod report;
+
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    panic::Location,
+    sync::Arc,
+};
+
+pub use placeholder::*;
+pub use report::*;
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use self::log::*;
+use crate::JSONGetTextValue;
+
+/// Why a text is reported to a `MissingTextHandler`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum MissingTextKind {
+    /// The text does not exist, so `None` is returned.
+    NotFound,
+    /// The text is not translated for the key, so the value of the default key is returned.
+    Untranslated,
+}
+
+impl MissingTextKind {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MissingTextKind::NotFound => "not_found",
+            MissingTextKind::Untranslated => "untranslated",
+        }
+    }
+}
+
+impl Display for MissingTextKind {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A text which is not found or not translated.
+#[derive(Debug, Clone, Copy)]
+pub struct MissingText<'e> {
+    /// The requested key.
+    pub key:      &'e str,
+    /// The id of the text.
+    pub text:     &'e str,
+    pub kind:     MissingTextKind,
+    /// Where the text is requested.
+    pub location: &'static Location<'static>,
+}
+
+/// A hook which is called when a text is not found or not translated by `get_text`, `get_text_with_key` or `get_multiple_text_with_key`.
+pub trait MissingTextHandler: Debug + Send + Sync {
+    /// Handle a missing text. If a value is returned, it is used instead of the value which would be returned.
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>>;
+}
+
+impl<H: MissingTextHandler + ?Sized> MissingTextHandler for Arc<H> {
+    #[inline]
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>> {
+        (**self).handle(missing)
+    }
+}
+
+impl<H: MissingTextHandler + ?Sized> MissingTextHandler for Box<H> {
+    #[inline]
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>> {
+        (**self).handle(missing)
+    }
+}
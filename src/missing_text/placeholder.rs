@@ -0,0 +1,47 @@
+use super::{MissingText, MissingTextHandler, MissingTextKind};
+use crate::JSONGetTextValue;
+
+/// A `MissingTextHandler` which returns a placeholder for every text which is not found, e.g. `[[missing:hello]]`. The `{key}` and `{text}` placeholders in the template are replaced.
+#[derive(Debug, Clone)]
+pub struct PlaceholderMissingTextHandler {
+    template:     String,
+    untranslated: bool,
+}
+
+impl PlaceholderMissingTextHandler {
+    /// Create a handler with a template such as `[[missing:{text}]]`.
+    #[inline]
+    pub fn new<S: Into<String>>(template: S) -> Self {
+        PlaceholderMissingTextHandler {
+            template: template.into(), untranslated: false
+        }
+    }
+
+    /// Also return the placeholder for texts which are not translated, instead of the values of the default key.
+    #[inline]
+    pub fn with_untranslated(mut self, untranslated: bool) -> Self {
+        self.untranslated = untranslated;
+
+        self
+    }
+}
+
+impl Default for PlaceholderMissingTextHandler {
+    #[inline]
+    fn default() -> Self {
+        PlaceholderMissingTextHandler::new("[[missing:{text}]]")
+    }
+}
+
+impl MissingTextHandler for PlaceholderMissingTextHandler {
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>> {
+        if missing.kind == MissingTextKind::Untranslated && !self.untranslated {
+            return None;
+        }
+
+        let placeholder =
+            crate::format_text(&self.template, &[("key", &missing.key), ("text", &missing.text)]);
+
+        Some(JSONGetTextValue::from_string(placeholder))
+    }
+}
@@ -0,0 +1,101 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use super::{MissingText, MissingTextHandler, MissingTextKind};
+use crate::JSONGetTextValue;
+
+/// A missing text collected by `MissingTextReport`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct MissingTextRecord {
+    pub key:    String,
+    pub text:   String,
+    pub kind:   &'static str,
+    pub file:   &'static str,
+    pub line:   u32,
+    pub column: u32,
+    /// How many times the text is requested at the location.
+    pub count:  u64,
+}
+
+/// A `MissingTextHandler` which collects missing texts in memory. Share it by an `Arc` to read the report.
+#[derive(Debug, Default)]
+pub struct MissingTextReport {
+    records: Mutex<HashMap<RecordKey, u64>>,
+}
+
+type RecordKey = (String, String, MissingTextKind, &'static str, u32, u32);
+
+impl MissingTextReport {
+    #[inline]
+    pub fn new() -> Self {
+        MissingTextReport::default()
+    }
+
+    /// Get the collected records, sorted by keys and texts.
+    pub fn records(&self) -> Vec<MissingTextRecord> {
+        let records = self.records.lock().unwrap();
+
+        let mut records: Vec<MissingTextRecord> = records
+            .iter()
+            .map(|((key, text, kind, file, line, column), count)| MissingTextRecord {
+                key: key.clone(),
+                text: text.clone(),
+                kind: kind.as_str(),
+                file,
+                line: *line,
+                column: *column,
+                count: *count,
+            })
+            .collect();
+
+        records.sort_unstable();
+
+        records
+    }
+
+    /// Export the collected records as a JSON array.
+    #[inline]
+    pub fn to_json(&self) -> String {
+        let records: Vec<serde_json::Value> = self
+            .records()
+            .into_iter()
+            .map(|record| {
+                serde_json::json!({
+                    "key": record.key,
+                    "text": record.text,
+                    "kind": record.kind,
+                    "file": record.file,
+                    "line": record.line,
+                    "column": record.column,
+                    "count": record.count,
+                })
+            })
+            .collect();
+
+        serde_json::Value::Array(records).to_string()
+    }
+
+    /// Remove all collected records.
+    #[inline]
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+impl MissingTextHandler for MissingTextReport {
+    fn handle(&self, missing: &MissingText<'_>) -> Option<JSONGetTextValue<'static>> {
+        let location = missing.location;
+
+        let key = (
+            missing.key.to_string(),
+            missing.text.to_string(),
+            missing.kind,
+            location.file(),
+            location.line(),
+            location.column(),
+        );
+
+        *self.records.lock().unwrap().entry(key).or_insert(0) += 1;
+
+        None
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use crate::{serde_json::Value, JSONGetTextValue};
+
+const RLO: char = '\u{202E}';
+const PDF: char = '\u{202C}';
+
+/// Options for generating a pseudo-locale. See [`pseudo_localize`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PseudoLocaleOptions {
+    /// Replace ASCII letters with accented ones, e.g. `Hello` becomes `Ḥḗŀŀǿ`.
+    pub accents:   bool,
+    /// Append padding to make texts longer by this percentage, in order to catch truncation.
+    pub expansion: u16,
+    /// Wrap texts in `[` and `]`, so that hard-coded or concatenated texts can be found easily.
+    pub brackets:  bool,
+    /// Wrap every run of words in RLO (U+202E) and PDF (U+202C), so that texts are displayed mirrored like a right-to-left language.
+    pub mirror:    bool,
+}
+
+impl PseudoLocaleOptions {
+    /// The options for an accented pseudo-locale such as `en_XA`. Letters are accented, texts are 30% longer and wrapped in brackets.
+    #[inline]
+    pub const fn accented() -> PseudoLocaleOptions {
+        PseudoLocaleOptions {
+            accents: true, expansion: 30, brackets: true, mirror: false
+        }
+    }
+
+    /// The options for a mirrored pseudo-locale such as `ar_XB`. Texts are displayed from right to left.
+    #[inline]
+    pub const fn mirrored() -> PseudoLocaleOptions {
+        PseudoLocaleOptions {
+            accents: false, expansion: 0, brackets: false, mirror: true
+        }
+    }
+}
+
+impl Default for PseudoLocaleOptions {
+    #[inline]
+    fn default() -> Self {
+        PseudoLocaleOptions::accented()
+    }
+}
+
+/**
+Convert a text to a pseudo-localized one. Placeholders (`{name}`, including nested ICU arguments), printf-style formats (`%s`, `%1$d`), HTML tags and HTML entities are kept intact.
+
+```rust
+use json_gettext::{pseudo_localize, PseudoLocaleOptions};
+
+let options = PseudoLocaleOptions {
+    expansion: 0, ..PseudoLocaleOptions::accented()
+};
+
+assert_eq!("[Ḥḗŀŀǿ, {name}! <b>ǿķ</b>]", pseudo_localize("Hello, {name}! <b>ok</b>", &options));
+```
+*/
+pub fn pseudo_localize<S: AsRef<str>>(text: S, options: &PseudoLocaleOptions) -> String {
+    let text = text.as_ref();
+
+    let mut s = String::with_capacity(text.len() * 2);
+
+    if options.brackets {
+        s.push('[');
+    }
+
+    let mut visible = 0usize;
+    let mut in_run = false;
+    let mut remaining = text;
+
+    while let Some(c) = remaining.chars().next() {
+        let protected = protected_len(remaining);
+
+        if protected > 0 {
+            if in_run {
+                s.push(PDF);
+                in_run = false;
+            }
+
+            s.push_str(&remaining[..protected]);
+            remaining = &remaining[protected..];
+
+            continue;
+        }
+
+        if options.mirror && !in_run && !c.is_whitespace() {
+            s.push(RLO);
+            in_run = true;
+        }
+
+        s.push(if options.accents { accent(c) } else { c });
+        visible += 1;
+        remaining = &remaining[c.len_utf8()..];
+    }
+
+    let padding = (visible * options.expansion as usize).div_ceil(100);
+
+    if padding > 0 {
+        if options.mirror && !in_run {
+            s.push(RLO);
+            in_run = true;
+        }
+
+        s.extend(std::iter::repeat_n('~', padding));
+    }
+
+    if in_run {
+        s.push(PDF);
+    }
+
+    if options.brackets {
+        s.push(']');
+    }
+
+    s
+}
+
+/// Convert a value to a pseudo-localized one. Strings in arrays and objects (e.g. plural objects) are converted too.
+pub fn pseudo_localize_value(
+    value: &JSONGetTextValue,
+    options: &PseudoLocaleOptions,
+) -> JSONGetTextValue<'static> {
+    match value {
+        JSONGetTextValue::Str(s) => JSONGetTextValue::from_string(pseudo_localize(s, options)),
+        JSONGetTextValue::JSONValue(v) => {
+            JSONGetTextValue::from_json_value(pseudo_localize_json_value(v, options))
+        },
+        JSONGetTextValue::JSONValueRef(v) => {
+            JSONGetTextValue::from_json_value(pseudo_localize_json_value(v, options))
+        },
+        JSONGetTextValue::Tree(v) => JSONGetTextValue::from_json_value(pseudo_localize_json_value(
+            &v.to_json_value(),
+            options,
+        )),
+        JSONGetTextValue::TreeRef(v) => JSONGetTextValue::from_json_value(
+            pseudo_localize_json_value(&v.to_json_value(), options),
+        ),
+    }
+}
+
+/// Convert all values in a text map to pseudo-localized ones. See [`pseudo_localize`].
+#[inline]
+pub fn pseudo_localize_map<
+    'b,
+    'a: 'b,
+    I: IntoIterator<Item = (&'b str, &'b JSONGetTextValue<'a>)>,
+>(
+    map: I,
+    options: &PseudoLocaleOptions,
+) -> HashMap<String, JSONGetTextValue<'static>> {
+    map.into_iter()
+        .map(|(text, value)| (String::from(text), pseudo_localize_value(value, options)))
+        .collect()
+}
+
+fn pseudo_localize_json_value(value: &Value, options: &PseudoLocaleOptions) -> Value {
+    match value {
+        Value::String(s) => Value::String(pseudo_localize(s, options)),
+        Value::Array(array) => {
+            Value::Array(array.iter().map(|v| pseudo_localize_json_value(v, options)).collect())
+        },
+        Value::Object(map) => Value::Object(
+            map.iter().map(|(k, v)| (k.clone(), pseudo_localize_json_value(v, options))).collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+/// Get the length of the placeholder, format, tag or entity at the start of the text. `0` means there is none.
+fn protected_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+
+    match bytes[0] {
+        b'{' => {
+            let mut depth = 0usize;
+
+            for (i, b) in bytes.iter().enumerate() {
+                match b {
+                    b'{' => depth += 1,
+                    b'}' => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return i + 1;
+                        }
+                    },
+                    _ => (),
+                }
+            }
+
+            0
+        },
+        b'<' => match text.find('>') {
+            Some(end) if end > 1 && !bytes[1].is_ascii_whitespace() => end + 1,
+            _ => 0,
+        },
+        b'&' => match text.find(';') {
+            Some(end)
+                if end > 1
+                    && bytes[1..end].iter().all(|b| b.is_ascii_alphanumeric() || *b == b'#') =>
+            {
+                end + 1
+            },
+            _ => 0,
+        },
+        b'%' => {
+            if bytes.get(1) == Some(&b'%') {
+                return 2;
+            }
+
+            let mut i = 1;
+
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || b"$-+#.".contains(&bytes[i])) {
+                i += 1;
+            }
+
+            match bytes.get(i) {
+                Some(b) if b.is_ascii_alphabetic() || *b == b'@' => i + 1,
+                _ => 0,
+            }
+        },
+        _ => 0,
+    }
+}
+
+#[inline]
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'ȧ',
+        'b' => 'ƀ',
+        'c' => 'ƈ',
+        'd' => 'ḓ',
+        'e' => 'ḗ',
+        'f' => 'ƒ',
+        'g' => 'ɠ',
+        'h' => 'ħ',
+        'i' => 'ī',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ŀ',
+        'm' => 'ḿ',
+        'n' => 'ƞ',
+        'o' => 'ǿ',
+        'p' => 'ƥ',
+        'q' => 'ɋ',
+        'r' => 'ř',
+        's' => 'ş',
+        't' => 'ŧ',
+        'u' => 'ŭ',
+        'v' => 'ṽ',
+        'w' => 'ẇ',
+        'x' => 'ẋ',
+        'y' => 'ẏ',
+        'z' => 'ẑ',
+        'A' => 'Ȧ',
+        'B' => 'Ɓ',
+        'C' => 'Ƈ',
+        'D' => 'Ḓ',
+        'E' => 'Ḗ',
+        'F' => 'Ƒ',
+        'G' => 'Ɠ',
+        'H' => 'Ḥ',
+        'I' => 'Ī',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ŀ',
+        'M' => 'Ḿ',
+        'N' => 'Ƞ',
+        'O' => 'Ǿ',
+        'P' => 'Ƥ',
+        'Q' => 'Ɋ',
+        'R' => 'Ř',
+        'S' => 'Ş',
+        'T' => 'Ŧ',
+        'U' => 'Ŭ',
+        'V' => 'Ṽ',
+        'W' => 'Ẇ',
+        'X' => 'Ẋ',
+        'Y' => 'Ẏ',
+        'Z' => 'Ẑ',
+        _ => c,
+    }
+}
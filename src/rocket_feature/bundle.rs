@@ -0,0 +1,182 @@
+use std::{borrow::Cow, collections::BTreeMap, io::Cursor, marker::PhantomData};
+
+use regex::{Regex, RegexBuilder};
+use rocket::{
+    data::Data,
+    http::{ContentType, Header, Method, Status},
+    request::Request,
+    response::{self, Responder, Response},
+    route::{self, Handler, Route},
+};
+
+use super::{JSONGetTextManager, KeyParam};
+use crate::{IntoLocaleKey, JSONGetTextValue, LocaleKey};
+
+/// The default `Cache-Control` header of bundles. Clients always revalidate with the ETag, so they get the new texts as soon as the catalog is reloaded.
+pub const DEFAULT_BUNDLE_CACHE_CONTROL: &str = "no-cache";
+
+/// The size limit of a compiled `?filter=` regex, so that a request cannot make the server compile a huge regex.
+const FILTER_SIZE_LIMIT: usize = 64 * 1024;
+
+/// The size limit of the lazy DFA of a `?filter=` regex.
+const FILTER_DFA_SIZE_LIMIT: usize = 256 * 1024;
+
+/// The texts of a key (all of them, or the ones whose IDs match a regex) responded as one JSON object, with a strong ETag, `Cache-Control` and `Vary: Accept-Language`. If the `If-None-Match` header of the request matches the ETag, `304 Not Modified` is responded without a body.
+#[derive(Debug, Clone)]
+pub struct JSONGetTextBundle {
+    json:          String,
+    etag:          String,
+    cache_control: Cow<'static, str>,
+}
+
+impl JSONGetTextBundle {
+    /// The JSON object of the texts. Text IDs are sorted, so the same texts always give the same JSON.
+    #[inline]
+    pub fn as_json(&self) -> &str {
+        self.json.as_str()
+    }
+
+    /// The strong ETag (including the double quotes), derived from the catalog generation and the content.
+    #[inline]
+    pub fn etag(&self) -> &str {
+        self.etag.as_str()
+    }
+
+    /// Set the `Cache-Control` header. It is [`DEFAULT_BUNDLE_CACHE_CONTROL`] by default.
+    #[inline]
+    pub fn with_cache_control<S: Into<Cow<'static, str>>>(mut self, cache_control: S) -> Self {
+        self.cache_control = cache_control.into();
+
+        self
+    }
+
+    /// Returns `true` if an `If-None-Match` header value matches the ETag.
+    fn matches(&self, if_none_match: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == self.etag)
+    }
+}
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Create a bundle of the texts of a key. Only the texts whose IDs match `filter` are included if it is given. The key is resolved like [`JSONGetText::get`](crate::JSONGetText::get) does.
+    pub fn bundle<Q: IntoLocaleKey<Key = K>>(
+        &self,
+        key: Q,
+        filter: Option<&Regex>,
+    ) -> JSONGetTextBundle {
+        let json_gettext = self.share();
+
+        let map = json_gettext.get_by_key(&key.into_locale_key().normalize());
+
+        let texts: BTreeMap<&str, &JSONGetTextValue> = map
+            .iter()
+            .filter(|(text, _)| filter.map(|regex| regex.is_match(text)).unwrap_or(true))
+            .collect();
+
+        let json = crate::serde_json::to_string(&texts).unwrap();
+
+        let etag = format!("\"{:x}-{:016x}\"", self.generation(), crate::fnv1a64(json.as_bytes()));
+
+        JSONGetTextBundle {
+            json,
+            etag,
+            cache_control: Cow::Borrowed(DEFAULT_BUNDLE_CACHE_CONTROL),
+        }
+    }
+
+    /// Create the routes which respond bundles, with [`DEFAULT_BUNDLE_CACHE_CONTROL`]. See [`bundle_routes_with_cache_control`](Self::bundle_routes_with_cache_control).
+    #[inline]
+    pub fn bundle_routes() -> Vec<Route> {
+        Self::bundle_routes_with_cache_control(DEFAULT_BUNDLE_CACHE_CONTROL)
+    }
+
+    /// Create the routes which respond bundles. After being mounted at a base, e.g. `/i18n`, `GET /i18n/<key>` responds all the texts of a key and `GET /i18n/<key>?filter=<regex>` responds the ones whose IDs match the regex. Regexes which compile to more than 64 KiB are rejected with `400 Bad Request`. The `JSONGetTextManager<K>` state must be managed, e.g. by its fairing.
+    pub fn bundle_routes_with_cache_control<S: Into<Cow<'static, str>>>(
+        cache_control: S,
+    ) -> Vec<Route> {
+        let handler = BundleHandler::<K> {
+            cache_control: cache_control.into(),
+            _key:          PhantomData,
+        };
+
+        vec![Route::new(Method::Get, "/<key>", handler)]
+    }
+}
+
+#[derive(Debug)]
+struct BundleHandler<K: LocaleKey> {
+    cache_control: Cow<'static, str>,
+    _key:          PhantomData<fn() -> K>,
+}
+
+impl<K: LocaleKey> Clone for BundleHandler<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        BundleHandler {
+            cache_control: self.cache_control.clone(), _key: PhantomData
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl<K: LocaleKey> Handler for BundleHandler<K> {
+    async fn handle<'r>(&self, req: &'r Request<'_>, data: Data<'r>) -> route::Outcome<'r> {
+        let manager = match req.rocket().state::<JSONGetTextManager<K>>() {
+            Some(manager) => manager,
+            None => {
+                rocket::error!("JSONGetTextManager is not managed.");
+
+                return route::Outcome::Error(Status::InternalServerError);
+            },
+        };
+
+        let key = match req.param::<KeyParam<K>>(0) {
+            Some(Ok(key)) => key.into_inner(),
+            _ => return route::Outcome::forward(data, Status::NotFound),
+        };
+
+        let filter = match req.query_value::<&str>("filter") {
+            Some(Ok(filter)) => match RegexBuilder::new(filter)
+                .size_limit(FILTER_SIZE_LIMIT)
+                .dfa_size_limit(FILTER_DFA_SIZE_LIMIT)
+                .build()
+            {
+                Ok(regex) => Some(regex),
+                Err(_) => return route::Outcome::Error(Status::BadRequest),
+            },
+            Some(Err(_)) => return route::Outcome::Error(Status::BadRequest),
+            None => None,
+        };
+
+        let bundle =
+            manager.bundle(key, filter.as_ref()).with_cache_control(self.cache_control.clone());
+
+        route::Outcome::from(req, bundle)
+    }
+}
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for JSONGetTextBundle {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = Response::build();
+
+        response
+            .header(Header::new("ETag", self.etag.clone()))
+            .header(Header::new("Cache-Control", self.cache_control.clone()))
+            .raw_header("Vary", "Accept-Language");
+
+        let not_modified =
+            req.headers().get("If-None-Match").any(|if_none_match| self.matches(if_none_match));
+
+        if not_modified {
+            response.status(Status::NotModified);
+        } else {
+            let json = self.json;
+
+            response.header(ContentType::JSON).sized_body(json.len(), Cursor::new(json));
+        }
+
+        response.ok()
+    }
+}
@@ -8,19 +8,19 @@ use rocket::{
 };
 
 use super::JSONGetTextManager;
-use crate::Key;
+use crate::LocaleKey;
 
 const FAIRING_NAME: &str = "JSONGetText (Debug)";
 
 /// The fairing of `JSONGetTextManager`.
 #[allow(clippy::type_complexity)]
-pub struct JSONGetTextFairing {
+pub struct JSONGetTextFairing<K: LocaleKey = String> {
     pub(crate) custom_callback:
-        Box<dyn Fn() -> (Key, Vec<(Key, &'static str)>) + Send + Sync + 'static>,
+        Box<dyn Fn() -> (K, Vec<(K, &'static str)>) + Send + Sync + 'static>,
 }
 
 #[rocket::async_trait]
-impl Fairing for JSONGetTextFairing {
+impl<K: LocaleKey> Fairing for JSONGetTextFairing<K> {
     #[inline]
     fn info(&self) -> Info {
         Info {
@@ -32,18 +32,25 @@ impl Fairing for JSONGetTextFairing {
     async fn on_ignite(&self, rocket: Rocket<Build>) -> Result<Rocket<Build>, Rocket<Build>> {
         let (default_key, source) = (self.custom_callback)();
 
-        let state = JSONGetTextManager::from_files(default_key, source).unwrap();
+        match JSONGetTextManager::from_files(default_key, source) {
+            Ok(state) => Ok(rocket.manage(state)),
+            Err(err) => {
+                rocket::error!("Failed to build the JSONGetText context: {}", err);
 
-        Ok(rocket.manage(state))
+                Err(rocket)
+            },
+        }
     }
 
     #[inline]
     async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
         let ctx = req
             .rocket()
-            .state::<JSONGetTextManager>()
+            .state::<JSONGetTextManager<K>>()
             .expect("JSONGetTextManager registered in on_attach");
 
-        ctx.reload_if_needed().unwrap();
+        if let Err(err) = ctx.reload_if_needed() {
+            rocket::error!("Failed to reload the JSONGetText context: {}", err);
+        }
     }
 }
@@ -0,0 +1,134 @@
+extern crate rocket;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError, RwLock, TryLockError,
+    },
+    time::SystemTime,
+};
+
+use rocket::fairing::Fairing;
+
+use crate::{
+    JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextFairing, LocaleKey,
+};
+
+/// Cloning a `JSONGetTextManager` is cheap, and the clones share the same catalog.
+#[derive(Debug)]
+pub struct JSONGetTextManager<K: LocaleKey = String> {
+    inner: Arc<Inner<K>>,
+}
+
+#[derive(Debug)]
+struct Inner<K: LocaleKey> {
+    json_gettext: RwLock<Arc<JSONGetText<'static, K>>>,
+    /// Locked while the files are being checked or reloaded.
+    files:        Mutex<HashMap<K, (PathBuf, Option<SystemTime>)>>,
+    generation:   AtomicU64,
+}
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    pub fn from_files(
+        default_key: K,
+        source: Vec<(K, &'static str)>,
+    ) -> Result<JSONGetTextManager<K>, JSONGetTextBuildError> {
+        let mut builder = JSONGetTextBuilder::new(default_key);
+
+        let mut files = HashMap::with_capacity(source.len());
+
+        for (key, json_file) in source {
+            let metadata = Path::new(json_file).metadata()?;
+
+            let mtime = metadata.modified().ok();
+
+            builder.add_json_file(key.clone(), json_file)?;
+
+            let json_file_path: PathBuf = json_file.into();
+
+            files.insert(key, (json_file_path, mtime));
+        }
+
+        Ok(JSONGetTextManager {
+            inner: Arc::new(Inner {
+                json_gettext: RwLock::new(Arc::new(builder.build()?)),
+                files:        Mutex::new(files),
+                generation:   AtomicU64::new(0),
+            }),
+        })
+    }
+
+    /// Get the current `JSONGetText` instance, which can be shared across threads. Reloading replaces the instance of the manager, and the shared ones are not affected.
+    #[inline]
+    pub fn share(&self) -> Arc<JSONGetText<'static, K>> {
+        self.inner.json_gettext.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// The generation of the catalog. It is increased every time the JSON files are reloaded.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.inner.generation.load(Ordering::Relaxed)
+    }
+
+    /// Reload the JSON files if any of them has been modified. Returns `true` if they are reloaded, or `false` if nothing has changed or another reloading is in progress. If the reloading fails, the previous catalog is kept, and the files are not reloaded again until they are modified again.
+    pub fn reload_if_needed(&self) -> Result<bool, JSONGetTextBuildError> {
+        let mut files = match self.inner.files.try_lock() {
+            Ok(files) => files,
+            Err(TryLockError::Poisoned(err)) => err.into_inner(),
+            Err(TryLockError::WouldBlock) => return Ok(false),
+        };
+
+        let mut do_reload = false;
+
+        for (_, (path, mtime)) in files.iter_mut() {
+            let new_mtime = path.metadata()?.modified().ok();
+
+            match (*mtime, new_mtime) {
+                (Some(mtime), Some(new_mtime)) if new_mtime <= mtime => (),
+                _ => {
+                    *mtime = new_mtime;
+
+                    do_reload = true;
+                },
+            }
+        }
+
+        if do_reload {
+            let mut builder = JSONGetTextBuilder::new(self.share().get_default_key().clone());
+
+            for (key, (path, _)) in files.iter() {
+                builder.add_json_file(key.clone(), path)?;
+            }
+
+            let json_gettext = Arc::new(builder.build()?);
+
+            *self.inner.json_gettext.write().unwrap_or_else(PoisonError::into_inner) = json_gettext;
+
+            self.inner.generation.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(do_reload)
+    }
+}
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Create the fairing of `JSONGetTextManager`.
+    pub fn fairing<F>(f: F) -> impl Fairing
+    where
+        F: Fn() -> (K, Vec<(K, &'static str)>) + Send + Sync + 'static, {
+        JSONGetTextFairing {
+            custom_callback: Box::new(f)
+        }
+    }
+}
+
+impl<K: LocaleKey> Clone for JSONGetTextManager<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        JSONGetTextManager {
+            inner: self.inner.clone()
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{borrow::Cow, ops::Deref};
+
+use rocket::{
+    http::{Cookie, CookieJar, SameSite, Status},
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+};
+use serde::{Serialize, Serializer};
+
+use super::JSONGetTextManager;
+use crate::{LocaleConfig, LocaleKey, LocaleRequest, LocaleSource};
+
+impl LocaleConfig {
+    /// Remember a key in the cookie, e.g. when the user switches language.
+    pub fn set_cookie<K: LocaleKey>(&self, cookies: &CookieJar<'_>, key: &K) {
+        let cookie = Cookie::build((self.get_cookie_name().to_string(), key.to_key_string()))
+            .path("/")
+            .same_site(SameSite::Lax)
+            .permanent();
+
+        cookies.add(cookie);
+    }
+
+    /// Forget the key in the cookie.
+    #[inline]
+    pub fn remove_cookie(&self, cookies: &CookieJar<'_>) {
+        cookies.remove(Cookie::build(self.get_cookie_name().to_string()).path("/"));
+    }
+}
+
+/// A request guard which resolves the locale of a request from the sources in [`LocaleConfig`], which can be managed as a Rocket state to override the default one. A candidate is used only if the `JSONGetTextManager<K>` state has texts for it (aliases and fallback keys are resolved), and the key which has the texts is given. The default key is given if no candidate is usable.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Locale<K: LocaleKey = String> {
+    key:    K,
+    source: Option<LocaleSource>,
+}
+
+impl<K: LocaleKey> Locale<K> {
+    /// The source where the key is found. `None` means the key is the default key.
+    #[inline]
+    pub fn get_source(&self) -> Option<&LocaleSource> {
+        self.source.as_ref()
+    }
+
+    /// Unwrap the key.
+    #[inline]
+    pub fn into_inner(self) -> K {
+        self.key
+    }
+}
+
+impl<K: LocaleKey> Deref for Locale<K> {
+    type Target = K;
+
+    #[inline]
+    fn deref(&self) -> &K {
+        &self.key
+    }
+}
+
+/// A `Locale` is serialized as the string of its key, so it can be put into template contexts.
+impl<K: LocaleKey> Serialize for Locale<K> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.key.to_key_string())
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, K: LocaleKey> FromRequest<'r> for Locale<K> {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let manager = match req.rocket().state::<JSONGetTextManager<K>>() {
+            Some(manager) => manager,
+            None => {
+                rocket::error!("JSONGetTextManager is not managed.");
+
+                return Outcome::Error((Status::InternalServerError, ()));
+            },
+        };
+
+        let default_config = LocaleConfig::default();
+
+        let config = req.rocket().state::<LocaleConfig>().unwrap_or(&default_config);
+
+        let (key, source) = config.negotiate(&manager.share(), req);
+
+        Outcome::Success(Locale {
+            key,
+            source: source.cloned(),
+        })
+    }
+}
+
+impl<'r> LocaleRequest for Request<'r> {
+    #[inline]
+    fn path_segment(&self, index: usize) -> Option<Cow<'_, str>> {
+        self.routed_segment(index).map(Cow::Borrowed)
+    }
+
+    #[inline]
+    fn query_value(&self, name: &str) -> Option<Cow<'_, str>> {
+        match Request::query_value::<&str>(self, name) {
+            Some(Ok(value)) => Some(Cow::Borrowed(value)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn cookie(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.cookies().get(name).map(|cookie| Cow::Owned(cookie.value().to_string()))
+    }
+
+    #[inline]
+    fn accept_language(&self) -> Option<Cow<'_, str>> {
+        self.headers().get_one("Accept-Language").map(Cow::Borrowed)
+    }
+}
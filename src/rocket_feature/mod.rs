@@ -0,0 +1,68 @@
+extern crate rocket;
+
+mod bundle;
+#[cfg(debug_assertions)]
+mod debug;
+mod locale;
+
+#[cfg(not(debug_assertions))]
+mod release;
+#[cfg(any(feature = "tera", feature = "handlebars"))]
+mod templates;
+
+use std::ops::Deref;
+
+pub use bundle::*;
+#[cfg(debug_assertions)]
+pub use debug::*;
+pub use locale::*;
+#[cfg(not(debug_assertions))]
+pub use release::*;
+use rocket::{
+    form::{self, FromFormField, ValueField},
+    request::FromParam,
+};
+#[cfg(any(feature = "tera", feature = "handlebars"))]
+pub use templates::*;
+
+use crate::LocaleKey;
+
+/// A wrapper for parsing a key of any `LocaleKey` type from a path parameter or a form field.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyParam<K: LocaleKey = String>(pub K);
+
+impl<K: LocaleKey> KeyParam<K> {
+    /// Unwrap the key.
+    #[inline]
+    pub fn into_inner(self) -> K {
+        self.0
+    }
+}
+
+impl<K: LocaleKey> Deref for KeyParam<K> {
+    type Target = K;
+
+    #[inline]
+    fn deref(&self) -> &K {
+        &self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'v, K: LocaleKey> FromFormField<'v> for KeyParam<K> {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        match K::parse_key(field.value) {
+            Some(key) => Ok(KeyParam(key)),
+            None => Err(form::Error::validation("invalid key").into()),
+        }
+    }
+}
+
+impl<'a, K: LocaleKey> FromParam<'a> for KeyParam<K> {
+    type Error = &'a str;
+
+    #[inline]
+    fn from_param(v: &'a str) -> Result<Self, Self::Error> {
+        K::parse_key(v).map(KeyParam).ok_or(v)
+    }
+}
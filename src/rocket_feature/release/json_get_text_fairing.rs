@@ -6,19 +6,19 @@ use rocket::{
 };
 
 use super::JSONGetTextManager;
-use crate::Key;
+use crate::LocaleKey;
 
 const FAIRING_NAME: &str = "JSONGetText";
 
 /// The fairing of `JSONGetTextManager`.
 #[allow(clippy::type_complexity)]
-pub struct JSONGetTextFairing {
+pub struct JSONGetTextFairing<K: LocaleKey = String> {
     pub(crate) custom_callback:
-        Box<dyn Fn() -> (Key, Vec<(Key, &'static str)>) + Send + Sync + 'static>,
+        Box<dyn Fn() -> (K, Vec<(K, &'static str)>) + Send + Sync + 'static>,
 }
 
 #[rocket::async_trait]
-impl Fairing for JSONGetTextFairing {
+impl<K: LocaleKey> Fairing for JSONGetTextFairing<K> {
     #[inline]
     fn info(&self) -> Info {
         Info {
@@ -30,8 +30,13 @@ impl Fairing for JSONGetTextFairing {
     async fn on_ignite(&self, rocket: Rocket<Build>) -> Result<Rocket<Build>, Rocket<Build>> {
         let (default_key, source) = (self.custom_callback)();
 
-        let state = JSONGetTextManager::from_jsons(default_key, source).unwrap();
+        match JSONGetTextManager::from_jsons(default_key, source) {
+            Ok(state) => Ok(rocket.manage(state)),
+            Err(err) => {
+                rocket::error!("Failed to build the JSONGetText context: {}", err);
 
-        Ok(rocket.manage(state))
+                Err(rocket)
+            },
+        }
     }
 }
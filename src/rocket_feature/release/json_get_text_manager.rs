@@ -0,0 +1,71 @@
+extern crate rocket;
+
+use std::sync::Arc;
+
+use rocket::fairing::Fairing;
+
+use crate::{
+    JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextFairing, LocaleKey,
+};
+
+/// Cloning a `JSONGetTextManager` is cheap, and the clones share the same catalog.
+#[derive(Debug)]
+pub struct JSONGetTextManager<K: LocaleKey = String> {
+    json_gettext: Arc<JSONGetText<'static, K>>,
+}
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    #[inline]
+    pub fn from_jsons(
+        default_key: K,
+        source: Vec<(K, &'static str)>,
+    ) -> Result<JSONGetTextManager<K>, JSONGetTextBuildError> {
+        let mut builder = JSONGetTextBuilder::new(default_key);
+
+        for (key, json) in source {
+            builder.add_json(key, json)?;
+        }
+
+        Ok(JSONGetTextManager {
+            json_gettext: Arc::new(builder.build()?)
+        })
+    }
+
+    /// Get the `JSONGetText` instance, which can be shared across threads.
+    #[inline]
+    pub fn share(&self) -> Arc<JSONGetText<'static, K>> {
+        self.json_gettext.clone()
+    }
+
+    /// The generation of the catalog. It is always `0` because the embedded JSON files are never reloaded.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        0
+    }
+
+    /// The embedded JSON files cannot be modified, so this method does nothing and returns `false`.
+    #[inline]
+    pub fn reload_if_needed(&self) -> Result<bool, JSONGetTextBuildError> {
+        Ok(false)
+    }
+}
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Create the fairing of `JSONGetTextManager`.
+    pub fn fairing<F>(f: F) -> impl Fairing
+    where
+        F: Fn() -> (K, Vec<(K, &'static str)>) + Send + Sync + 'static, {
+        JSONGetTextFairing {
+            custom_callback: Box::new(f)
+        }
+    }
+}
+
+impl<K: LocaleKey> Clone for JSONGetTextManager<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        JSONGetTextManager {
+            json_gettext: self.json_gettext.clone()
+        }
+    }
+}
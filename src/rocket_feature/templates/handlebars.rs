@@ -0,0 +1,75 @@
+use rocket_dyn_templates::handlebars::{
+    Context, Handlebars, Helper, HelperDef, RenderContext, RenderError, RenderErrorReason,
+    ScopedJson,
+};
+
+use super::{translate, JSONGetTextManager, TEMPLATE_LOCALE_VARIABLE};
+use crate::{serde_json::Value, LocaleKey};
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Register the `t` and `t_plural` helpers to a Handlebars instance.
+    ///
+    /// * `{{t "hello" name="Len"}}` formats a text with the hash arguments.
+    /// * `{{t_plural "apples" 3}}` chooses the plural form for the count first, and `count` is also an argument.
+    ///
+    /// The locale is read from the `lang` hash argument, or the [`TEMPLATE_LOCALE_VARIABLE`] variable of the context. The default key is used if neither exists.
+    #[inline]
+    pub fn register_handlebars_helpers(&self, handlebars: &mut Handlebars<'_>) {
+        handlebars.register_helper(
+            "t",
+            Box::new(HandlebarsText {
+                manager: self.clone(), plural: false
+            }),
+        );
+        handlebars.register_helper(
+            "t_plural",
+            Box::new(HandlebarsText {
+                manager: self.clone(), plural: true
+            }),
+        );
+    }
+}
+
+struct HandlebarsText<K: LocaleKey> {
+    manager: JSONGetTextManager<K>,
+    plural:  bool,
+}
+
+impl<K: LocaleKey> HelperDef for HandlebarsText<K> {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let name = if self.plural { "t_plural" } else { "t" };
+
+        let text = h
+            .param(0)
+            .and_then(|param| param.value().as_str())
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex(name, 0))?;
+
+        let count = if self.plural {
+            Some(h.param(1).ok_or(RenderErrorReason::ParamNotFoundForIndex(name, 1))?.value())
+        } else {
+            None
+        };
+
+        let lang = match h.hash_get("lang") {
+            Some(lang) => lang.value().as_str(),
+            None => ctx.data().get(TEMPLATE_LOCALE_VARIABLE).and_then(Value::as_str),
+        };
+
+        let format_args: Vec<(&str, &Value)> = h
+            .hash()
+            .iter()
+            .filter(|(name, _)| **name != "lang")
+            .map(|(name, value)| (*name, value.value()))
+            .collect();
+
+        translate(&self.manager, lang, text, count, &format_args)
+            .map(|s| ScopedJson::Derived(Value::String(s)))
+            .map_err(|err| RenderErrorReason::Other(err).into())
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(feature = "handlebars")]
+mod handlebars;
+#[cfg(feature = "tera")]
+mod tera;
+
+use std::{fmt::Display, marker::PhantomData, sync::Arc};
+
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    Build, Rocket,
+};
+use rocket_dyn_templates::{Engines, Template};
+use unic_langid::LanguageIdentifier;
+
+use super::JSONGetTextManager;
+use crate::{format_text, serde_json::Value, JSONGetTextValue, LocaleKey};
+
+const FAIRING_NAME: &str = "JSONGetText Templates";
+
+/// The name of the context variable which the Handlebars helpers read the locale from, if the `lang` hash argument is not given. A [`Locale`](super::Locale) can be put into a context as it is.
+pub const TEMPLATE_LOCALE_VARIABLE: &str = "lang";
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Create a fairing which attaches `rocket_dyn_templates` with the `t` and `t_plural` template functions (Tera) / helpers (Handlebars) registered. It must be attached after the fairing of `JSONGetTextManager`, and replaces `Template::fairing()`.
+    #[inline]
+    pub fn templates_fairing() -> impl Fairing {
+        Self::templates_fairing_with(|_| ())
+    }
+
+    /// Create a fairing like [`templates_fairing`](Self::templates_fairing) does, and customize the engines with `f`, like `Template::custom`.
+    pub fn templates_fairing_with<F>(f: F) -> impl Fairing
+    where
+        F: Fn(&mut Engines) + Send + Sync + 'static, {
+        JSONGetTextTemplatesFairing::<K> {
+            custom_callback: Arc::new(f),
+            _key:            PhantomData,
+        }
+    }
+
+    /// Register the `t` and `t_plural` template functions / helpers to all the engines.
+    #[inline]
+    pub fn register_templates(&self, engines: &mut Engines) {
+        #[cfg(feature = "tera")]
+        self.register_tera_functions(&mut engines.tera);
+
+        #[cfg(feature = "handlebars")]
+        self.register_handlebars_helpers(&mut engines.handlebars);
+    }
+}
+
+/// The fairing created by [`JSONGetTextManager::templates_fairing`].
+#[allow(clippy::type_complexity)]
+struct JSONGetTextTemplatesFairing<K: LocaleKey> {
+    custom_callback: Arc<dyn Fn(&mut Engines) + Send + Sync + 'static>,
+    _key:            PhantomData<fn() -> K>,
+}
+
+#[rocket::async_trait]
+impl<K: LocaleKey> Fairing for JSONGetTextTemplatesFairing<K> {
+    #[inline]
+    fn info(&self) -> Info {
+        Info {
+            name: FAIRING_NAME, kind: Kind::Ignite
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> Result<Rocket<Build>, Rocket<Build>> {
+        let manager = match rocket.state::<JSONGetTextManager<K>>() {
+            Some(manager) => manager.clone(),
+            None => {
+                rocket::error!(
+                    "JSONGetTextManager is not managed. Attach its fairing before the templates \
+                     fairing."
+                );
+
+                return Err(rocket);
+            },
+        };
+
+        let custom_callback = self.custom_callback.clone();
+
+        Ok(rocket.attach(Template::custom(move |engines: &mut Engines| {
+            manager.register_templates(engines);
+
+            custom_callback(engines);
+        })))
+    }
+}
+
+/// A template argument written as a text argument. Strings are written without quotes.
+struct Arg<'v>(&'v Value);
+
+impl<'v> Display for Arg<'v> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Value::String(s) => f.write_str(s),
+            value => Display::fmt(value, f),
+        }
+    }
+}
+
+/// Choose the value of a plural text for a count. Plural texts are objects whose keys are plural categories. The category of the count is chosen by the CLDR plural rules of the key, and `other` is used if the category does not exist or the key has no plural rules. Texts which are not objects are used for all counts.
+fn select_plural<'v, K: LocaleKey>(
+    key: &K,
+    value: &JSONGetTextValue<'v>,
+    count: &Value,
+) -> Option<JSONGetTextValue<'v>> {
+    if value.iter_object().is_none() {
+        return Some(value.clone());
+    }
+
+    let category = plural_category(key, count).unwrap_or(PluralCategory::OTHER);
+
+    let category = match category {
+        PluralCategory::ZERO => "zero",
+        PluralCategory::ONE => "one",
+        PluralCategory::TWO => "two",
+        PluralCategory::FEW => "few",
+        PluralCategory::MANY => "many",
+        PluralCategory::OTHER => "other",
+    };
+
+    value.get(category).or_else(|| value.get("other")).map(JSONGetTextValue::into_owned)
+}
+
+/// Find the CLDR plural category of a count (a number or a numeric string) for a key, e.g. `en_US` or `zh-TW`.
+fn plural_category<K: LocaleKey>(key: &K, count: &Value) -> Option<PluralCategory> {
+    let mut langid = key.to_key_string().parse::<LanguageIdentifier>().ok()?;
+
+    // the rules are defined for languages (and a few regions, e.g. `pt-PT`), so the subtags are removed until some rules are found
+    let rules = loop {
+        if let Ok(rules) = PluralRules::create(langid.clone(), PluralRuleType::CARDINAL) {
+            break rules;
+        }
+
+        if langid.variants().next().is_some() {
+            langid.clear_variants();
+        } else if langid.region.is_some() {
+            langid.region = None;
+        } else if langid.script.is_some() {
+            langid.script = None;
+        } else {
+            return None;
+        }
+    };
+
+    let category = match count {
+        Value::Number(n) => rules.select(n.to_string().as_str()),
+        Value::String(s) => rules.select(s.as_str()),
+        _ => return None,
+    };
+
+    category.ok()
+}
+
+/// Get a text of a locale (the default key if it is not given or cannot be parsed) and format it with the arguments. `count` is also an argument named `count`.
+fn translate<K: LocaleKey>(
+    manager: &JSONGetTextManager<K>,
+    lang: Option<&str>,
+    text: &str,
+    count: Option<&Value>,
+    args: &[(&str, &Value)],
+) -> Result<String, String> {
+    let json_gettext = manager.share();
+
+    let key = lang
+        .and_then(K::parse_key)
+        .map(K::normalize)
+        .unwrap_or_else(|| json_gettext.get_default_key().clone());
+
+    let map = json_gettext.get_by_key(&key);
+
+    let value = map.get(text).ok_or_else(|| format!("The text `{}` is not found.", text))?;
+
+    let value = match count {
+        Some(count) => select_plural(
+            json_gettext.resolve_key(&key).unwrap_or_else(|| json_gettext.get_default_key()),
+            value,
+            count,
+        )
+        .ok_or_else(|| format!("The text `{}` has no `other` plural form.", text))?,
+        None => value.clone(),
+    };
+
+    let count_arg = count.map(Arg);
+    let args: Vec<(&str, Arg)> = args.iter().map(|(name, value)| (*name, Arg(value))).collect();
+
+    let mut named_args: Vec<(&str, &dyn Display)> =
+        args.iter().map(|(name, arg)| (*name, arg as &dyn Display)).collect();
+
+    if let Some(count_arg) = count_arg.as_ref() {
+        named_args.push(("count", count_arg));
+    }
+
+    match value.as_str() {
+        Some(s) => Ok(format_text(s, &named_args)),
+        None => Ok(format_text(value.to_json_string(), &named_args)),
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use rocket_dyn_templates::tera::{self, Function, Tera, Value};
+
+use super::{translate, JSONGetTextManager};
+use crate::LocaleKey;
+
+impl<K: LocaleKey> JSONGetTextManager<K> {
+    /// Register the `t` and `t_plural` functions to a Tera instance.
+    ///
+    /// * `t(text="hello", lang=lang, name="Len")` formats a text with the other arguments.
+    /// * `t_plural(text="apples", count=3, lang=lang)` chooses the plural form for `count` first, and `count` is also an argument.
+    ///
+    /// The default key is used if `lang` is not given.
+    #[inline]
+    pub fn register_tera_functions(&self, tera: &mut Tera) {
+        tera.register_function("t", TeraText {
+            manager: self.clone(), plural: false
+        });
+        tera.register_function("t_plural", TeraText {
+            manager: self.clone(), plural: true
+        });
+    }
+}
+
+struct TeraText<K: LocaleKey> {
+    manager: JSONGetTextManager<K>,
+    plural:  bool,
+}
+
+impl<K: LocaleKey> Function for TeraText<K> {
+    fn call(&self, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        let name = if self.plural { "t_plural" } else { "t" };
+
+        let text = args
+            .get("text")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg(format!("`{}` requires a `text` string.", name)))?;
+
+        let count = if self.plural {
+            Some(
+                args.get("count")
+                    .ok_or_else(|| tera::Error::msg(format!("`{}` requires a `count`.", name)))?,
+            )
+        } else {
+            None
+        };
+
+        let lang = args.get("lang").and_then(Value::as_str);
+
+        let format_args: Vec<(&str, &Value)> = args
+            .iter()
+            .filter(|(name, _)| !matches!(name.as_str(), "text" | "lang" | "count"))
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+
+        translate(&self.manager, lang, text, count, &format_args)
+            .map(Value::String)
+            .map_err(tera::Error::msg)
+    }
+}
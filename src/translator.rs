@@ -0,0 +1,87 @@
+use std::{fmt::Display, sync::Arc};
+
+use crate::{
+    format_text, HTMLText, JSONGetText, JSONGetTextMap, JSONGetTextValue, LocaleKey, LocaleSource,
+};
+
+/// A handle of a shared `JSONGetText` instance bound to the locale of a request.
+#[derive(Debug, Clone)]
+pub struct Translator<K: LocaleKey = String> {
+    ctx:    Arc<JSONGetText<'static, K>>,
+    key:    K,
+    source: Option<LocaleSource>,
+}
+
+impl<K: LocaleKey> Translator<K> {
+    /// Create a `Translator` instance bound to a key.
+    #[inline]
+    pub fn new(ctx: Arc<JSONGetText<'static, K>>, key: K) -> Translator<K> {
+        Translator {
+            ctx,
+            key: key.normalize(),
+            source: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_source(mut self, source: Option<LocaleSource>) -> Self {
+        self.source = source;
+
+        self
+    }
+
+    /// The key which the handle is bound to.
+    #[inline]
+    pub fn get_key(&self) -> &K {
+        &self.key
+    }
+
+    /// The source where the key is found. `None` means the key is the default key or given directly.
+    #[inline]
+    pub fn get_source(&self) -> Option<&LocaleSource> {
+        self.source.as_ref()
+    }
+
+    /// The shared `JSONGetText` instance.
+    #[inline]
+    pub fn get_context(&self) -> &Arc<JSONGetText<'static, K>> {
+        &self.ctx
+    }
+
+    /// Get the text map of the key.
+    #[inline]
+    pub fn get(&self) -> JSONGetTextMap<'_, 'static> {
+        self.ctx.get_by_key(&self.key)
+    }
+
+    /// Get text of the key.
+    #[inline]
+    #[track_caller]
+    pub fn t<T: AsRef<str>>(&self, text: T) -> Option<JSONGetTextValue<'_>> {
+        self.ctx.get_text_by_key(&self.key, text.as_ref())
+    }
+
+    /// Get text of the key and replace its `{name}` placeholders with the arguments, see [`format_text`]. `None` is returned if the text is not found or not a string.
+    #[inline]
+    #[track_caller]
+    pub fn t_with<T: AsRef<str>>(&self, text: T, args: &[(&str, &dyn Display)]) -> Option<String> {
+        let value = self.t(text)?;
+
+        Some(format_text(value.as_str()?, args))
+    }
+
+    /// Render text of the key as HTML, see [`JSONGetText::render_html`].
+    #[inline]
+    #[track_caller]
+    pub fn t_html<T: AsRef<str>>(
+        &self,
+        text: T,
+        args: &[(&str, &dyn Display)],
+    ) -> Option<HTMLText> {
+        let text = text.as_ref();
+
+        let value = self.t(text)?;
+
+        Some(crate::html::render_text_html(text, value.as_str()?, args))
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use crate::serde_json::ser::{CompactFormatter, Formatter};
+
+/// Options for escaping JSON strings. Strings are always escaped as RFC 8259 requires. The options enable extra escapes which are still valid JSON.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct JSONEscapeOptions {
+    /// Escape `/` as `\/`.
+    pub escape_slash:           bool,
+    /// Escape `<`, `>` and `&` as `\u003c`, `\u003e` and `\u0026`, so that the JSON can be embedded in a `<script>` element safely.
+    pub html_safe:              bool,
+    /// Escape the line separator U+2028 and the paragraph separator U+2029 as `\u2028` and `\u2029`, which end a line in JavaScript before ES2019.
+    pub escape_line_separators: bool,
+}
+
+impl JSONEscapeOptions {
+    /// The options for embedding JSON in HTML. `/`, `<`, `>`, `&`, U+2028 and U+2029 are escaped.
+    #[inline]
+    pub const fn html() -> JSONEscapeOptions {
+        JSONEscapeOptions {
+            escape_slash:           true,
+            html_safe:              true,
+            escape_line_separators: true,
+        }
+    }
+}
+
+/// A compact `serde_json` formatter which applies `JSONEscapeOptions`.
+pub(crate) struct EscapeFormatter {
+    pub(crate) options: JSONEscapeOptions,
+}
+
+impl Formatter for EscapeFormatter {
+    fn write_string_fragment<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        let options = self.options;
+
+        if !options.escape_slash && !options.html_safe && !options.escape_line_separators {
+            return CompactFormatter.write_string_fragment(writer, fragment);
+        }
+
+        let bytes = fragment.as_bytes();
+
+        let mut from = 0;
+
+        for (i, b) in bytes.iter().enumerate() {
+            let (escaped, len): (&[u8], usize) = match b {
+                b'/' if options.escape_slash => (b"\\/", 1),
+                b'<' if options.html_safe => (b"\\u003c", 1),
+                b'>' if options.html_safe => (b"\\u003e", 1),
+                b'&' if options.html_safe => (b"\\u0026", 1),
+                // U+2028 and U+2029 are encoded as E2 80 A8 and E2 80 A9 in UTF-8
+                0xE2 if options.escape_line_separators => match bytes.get(i + 1..i + 3) {
+                    Some([0x80, 0xA8]) => (b"\\u2028", 3),
+                    Some([0x80, 0xA9]) => (b"\\u2029", 3),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            writer.write_all(&bytes[from..i])?;
+            writer.write_all(escaped)?;
+
+            from = i + len;
+        }
+
+        writer.write_all(&bytes[from..])
+    }
+}
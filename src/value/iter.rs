@@ -0,0 +1,110 @@
+use std::slice;
+
+use super::{JSONGetTextValue, JSONTree, JSONTreeStr};
+use crate::serde_json::{map, Value};
+
+/// An iterator over the elements of an array, which is either a `serde_json::Value` or a `JSONTree`. See [`JSONGetTextValue::iter_array`].
+#[derive(Debug, Clone)]
+pub struct JSONGetTextArrayIter<'v> {
+    inner: ArrayIterInner<'v>,
+}
+
+#[derive(Debug, Clone)]
+enum ArrayIterInner<'v> {
+    Value(slice::Iter<'v, Value>),
+    Tree(slice::Iter<'v, JSONTree<'v>>),
+}
+
+impl<'v> JSONGetTextArrayIter<'v> {
+    #[inline]
+    pub(super) fn from_value(array: &'v [Value]) -> JSONGetTextArrayIter<'v> {
+        JSONGetTextArrayIter {
+            inner: ArrayIterInner::Value(array.iter())
+        }
+    }
+
+    #[inline]
+    pub(super) fn from_tree(array: &'v [JSONTree<'v>]) -> JSONGetTextArrayIter<'v> {
+        JSONGetTextArrayIter {
+            inner: ArrayIterInner::Tree(array.iter())
+        }
+    }
+}
+
+impl<'v> Iterator for JSONGetTextArrayIter<'v> {
+    type Item = JSONGetTextValue<'v>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            ArrayIterInner::Value(iter) => iter.next().map(JSONGetTextValue::JSONValueRef),
+            ArrayIterInner::Tree(iter) => iter.next().map(JSONGetTextValue::TreeRef),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            ArrayIterInner::Value(iter) => iter.size_hint(),
+            ArrayIterInner::Tree(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'v> ExactSizeIterator for JSONGetTextArrayIter<'v> {}
+
+/// An iterator over the entries of an object, which is either a `serde_json::Value` or a `JSONTree`. See [`JSONGetTextValue::iter_object`].
+#[derive(Debug, Clone)]
+pub struct JSONGetTextObjectIter<'v> {
+    inner: ObjectIterInner<'v>,
+}
+
+#[derive(Debug, Clone)]
+enum ObjectIterInner<'v> {
+    Value(map::Iter<'v>),
+    Tree(slice::Iter<'v, (JSONTreeStr<'v>, JSONTree<'v>)>),
+}
+
+impl<'v> JSONGetTextObjectIter<'v> {
+    #[inline]
+    pub(super) fn from_value(map: &'v map::Map<String, Value>) -> JSONGetTextObjectIter<'v> {
+        JSONGetTextObjectIter {
+            inner: ObjectIterInner::Value(map.iter())
+        }
+    }
+
+    #[inline]
+    pub(super) fn from_tree(
+        entries: &'v [(JSONTreeStr<'v>, JSONTree<'v>)],
+    ) -> JSONGetTextObjectIter<'v> {
+        JSONGetTextObjectIter {
+            inner: ObjectIterInner::Tree(entries.iter())
+        }
+    }
+}
+
+impl<'v> Iterator for JSONGetTextObjectIter<'v> {
+    type Item = (&'v str, JSONGetTextValue<'v>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            ObjectIterInner::Value(iter) => {
+                iter.next().map(|(k, v)| (k.as_str(), JSONGetTextValue::JSONValueRef(v)))
+            },
+            ObjectIterInner::Tree(iter) => {
+                iter.next().map(|(k, v)| (k.as_str(), JSONGetTextValue::TreeRef(v)))
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            ObjectIterInner::Value(iter) => iter.size_hint(),
+            ObjectIterInner::Tree(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'v> ExactSizeIterator for JSONGetTextObjectIter<'v> {}
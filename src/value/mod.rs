@@ -1,5 +1,12 @@
 mod errors;
+mod escape;
+mod iter;
+mod ordering;
+mod tree;
 mod value_impl;
 
 pub use errors::*;
+pub use escape::*;
+pub use iter::*;
+pub use tree::*;
 pub use value_impl::*;
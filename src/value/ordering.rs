@@ -0,0 +1,301 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    slice,
+};
+
+use super::{value_impl::Node, JSONGetTextValue, JSONTree, JSONTreeStr};
+use crate::serde_json::{map, Map, Number, Value};
+
+/// The JSON content of a node. Arrays and objects are borrowed, so nothing is allocated to compare or hash them.
+enum Kind<'v> {
+    Null,
+    Bool(bool),
+    Number(&'v Number),
+    String(&'v str),
+    Array(Elements<'v>),
+    Object(Entries<'v>),
+}
+
+impl<'v> Kind<'v> {
+    #[inline]
+    fn rank(&self) -> u8 {
+        match self {
+            Kind::Null => 0,
+            Kind::Bool(_) => 1,
+            Kind::Number(_) => 2,
+            Kind::String(_) => 3,
+            Kind::Array(_) => 4,
+            Kind::Object(_) => 5,
+        }
+    }
+}
+
+impl<'v> Node<'v> {
+    fn kind(self) -> Kind<'v> {
+        match self {
+            Node::Str(s) => Kind::String(s),
+            Node::Value(v) => match v {
+                Value::Null => Kind::Null,
+                Value::Bool(b) => Kind::Bool(*b),
+                Value::Number(n) => Kind::Number(n),
+                Value::String(s) => Kind::String(s),
+                Value::Array(array) => Kind::Array(Elements::Value(array.iter())),
+                Value::Object(map) => Kind::Object(Entries::Value(map)),
+            },
+            Node::Tree(v) => match v {
+                JSONTree::Null => Kind::Null,
+                JSONTree::Bool(b) => Kind::Bool(*b),
+                JSONTree::Number(n) => Kind::Number(n),
+                JSONTree::String(s) => Kind::String(s),
+                JSONTree::Array(array) => Kind::Array(Elements::Tree(array.iter())),
+                JSONTree::Object(entries) => Kind::Object(Entries::Tree(entries)),
+            },
+        }
+    }
+}
+
+/// The elements of an array.
+enum Elements<'v> {
+    Value(slice::Iter<'v, Value>),
+    Tree(slice::Iter<'v, JSONTree<'v>>),
+}
+
+impl<'v> Iterator for Elements<'v> {
+    type Item = Node<'v>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Node<'v>> {
+        match self {
+            Elements::Value(iter) => iter.next().map(Node::Value),
+            Elements::Tree(iter) => iter.next().map(Node::Tree),
+        }
+    }
+}
+
+/// The entries of an object.
+#[derive(Clone, Copy)]
+enum Entries<'v> {
+    Value(&'v Map<String, Value>),
+    Tree(&'v [(JSONTreeStr<'v>, JSONTree<'v>)]),
+}
+
+impl<'v> Entries<'v> {
+    #[inline]
+    fn iter(self) -> EntriesIter<'v> {
+        match self {
+            Entries::Value(map) => EntriesIter::Value(map.iter()),
+            Entries::Tree(entries) => EntriesIter::Tree(entries.iter()),
+        }
+    }
+
+    /// Iterate over the entries in the order of their keys. If the entries are not already in that order, each step scans them for the next key instead of sorting a copy.
+    #[inline]
+    fn sorted(self) -> SortedEntries<'v> {
+        let mut keys = self.iter().map(|(k, _)| k);
+
+        let sorted = match keys.next() {
+            Some(mut previous) => keys.all(|k| {
+                let ordered = previous < k;
+
+                previous = k;
+
+                ordered
+            }),
+            None => true,
+        };
+
+        SortedEntries {
+            entries:  self,
+            iter:     if sorted { Some(self.iter()) } else { None },
+            previous: None,
+        }
+    }
+}
+
+enum EntriesIter<'v> {
+    Value(map::Iter<'v>),
+    Tree(slice::Iter<'v, (JSONTreeStr<'v>, JSONTree<'v>)>),
+}
+
+impl<'v> Iterator for EntriesIter<'v> {
+    type Item = (&'v str, Node<'v>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            EntriesIter::Value(iter) => iter.next().map(|(k, v)| (k.as_str(), Node::Value(v))),
+            EntriesIter::Tree(iter) => iter.next().map(|(k, v)| (k.as_str(), Node::Tree(v))),
+        }
+    }
+}
+
+/// The entries of an object in the order of their keys. If a key appears more than once, only its last entry is visited.
+struct SortedEntries<'v> {
+    entries:  Entries<'v>,
+    /// `Some` if the entries are already sorted.
+    iter:     Option<EntriesIter<'v>>,
+    previous: Option<&'v str>,
+}
+
+impl<'v> Iterator for SortedEntries<'v> {
+    type Item = (&'v str, Node<'v>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = &mut self.iter {
+            return iter.next();
+        }
+
+        let mut next: Option<(&'v str, Node<'v>)> = None;
+
+        for (k, v) in self.entries.iter() {
+            if self.previous.is_some_and(|p| k <= p) {
+                continue;
+            }
+
+            // `<=` rather than `<`, so the last entry of a duplicated key wins
+            if next.is_none_or(|(n, _)| k <= n) {
+                next = Some((k, v));
+            }
+        }
+
+        self.previous = next.map(|(k, _)| k);
+
+        next
+    }
+}
+
+/// A number as an integer if possible. Integers and floats are never equal, like `serde_json::Number`.
+enum NumberKey {
+    Integer(i128),
+    Float(f64),
+}
+
+impl NumberKey {
+    #[inline]
+    fn new(n: &Number) -> NumberKey {
+        match n.as_i64() {
+            Some(n) => NumberKey::Integer(n as i128),
+            None => match n.as_u64() {
+                Some(n) => NumberKey::Integer(n as i128),
+                None => NumberKey::Float(n.as_f64().unwrap_or(f64::NAN)),
+            },
+        }
+    }
+}
+
+fn cmp_numbers(a: &Number, b: &Number) -> Ordering {
+    match (NumberKey::new(a), NumberKey::new(b)) {
+        (NumberKey::Integer(a), NumberKey::Integer(b)) => a.cmp(&b),
+        (NumberKey::Float(a), NumberKey::Float(b)) => a.total_cmp(&b),
+        (NumberKey::Integer(a), NumberKey::Float(b)) => {
+            (a as f64).total_cmp(&b).then(Ordering::Less)
+        },
+        (NumberKey::Float(a), NumberKey::Integer(b)) => {
+            a.total_cmp(&(b as f64)).then(Ordering::Greater)
+        },
+    }
+}
+
+/// Compare two sequences lexicographically, like `Iterator::cmp` with a custom comparison.
+fn cmp_sequences<T, A: Iterator<Item = T>, B: Iterator<Item = T>>(
+    mut a: A,
+    mut b: B,
+    mut cmp: impl FnMut(T, T) -> Ordering,
+) -> Ordering {
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => match cmp(a, b) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            },
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+fn cmp_nodes(a: Node, b: Node) -> Ordering {
+    match (a.kind(), b.kind()) {
+        (Kind::Null, Kind::Null) => Ordering::Equal,
+        (Kind::Bool(a), Kind::Bool(b)) => a.cmp(&b),
+        (Kind::Number(a), Kind::Number(b)) => cmp_numbers(a, b),
+        (Kind::String(a), Kind::String(b)) => a.cmp(b),
+        (Kind::Array(a), Kind::Array(b)) => cmp_sequences(a, b, cmp_nodes),
+        (Kind::Object(a), Kind::Object(b)) => {
+            cmp_sequences(a.sorted(), b.sorted(), |(ak, av), (bk, bv)| {
+                ak.cmp(bk).then_with(|| cmp_nodes(av, bv))
+            })
+        },
+        (a, b) => a.rank().cmp(&b.rank()),
+    }
+}
+
+fn hash_node<H: Hasher>(node: Node, state: &mut H) {
+    let kind = node.kind();
+
+    kind.rank().hash(state);
+
+    match kind {
+        Kind::Null => (),
+        Kind::Bool(b) => b.hash(state),
+        Kind::Number(n) => match NumberKey::new(n) {
+            NumberKey::Integer(n) => n.hash(state),
+            NumberKey::Float(n) => n.to_bits().hash(state),
+        },
+        Kind::String(s) => s.hash(state),
+        Kind::Array(array) => {
+            let mut len = 0usize;
+
+            for v in array {
+                hash_node(v, state);
+                len += 1;
+            }
+
+            len.hash(state);
+        },
+        Kind::Object(entries) => {
+            let mut len = 0usize;
+
+            for (k, v) in entries.sorted() {
+                k.hash(state);
+                hash_node(v, state);
+                len += 1;
+            }
+
+            len.hash(state);
+        },
+    }
+}
+
+impl<'a, 'b> PartialEq<JSONGetTextValue<'b>> for JSONGetTextValue<'a> {
+    #[inline]
+    fn eq(&self, other: &JSONGetTextValue<'b>) -> bool {
+        cmp_nodes(self.node(), other.node()) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for JSONGetTextValue<'a> {}
+
+impl<'a> PartialOrd for JSONGetTextValue<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Values are ordered by their types first (`null`, booleans, numbers, strings, arrays, and then objects), and then by their content.
+impl<'a> Ord for JSONGetTextValue<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_nodes(self.node(), other.node())
+    }
+}
+
+impl<'a> Hash for JSONGetTextValue<'a> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_node(self.node(), state)
+    }
+}
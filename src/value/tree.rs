@@ -0,0 +1,659 @@
+use std::{
+    borrow::{Borrow, Cow},
+    cmp::Ordering,
+    collections::HashMap,
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    mem,
+    ops::{Deref, Index},
+    sync::Arc,
+};
+
+use serde::{
+    de::{Error as DeError, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    serde_json::{self, Number, Value},
+    JSONGetTextValue,
+};
+
+/// A string in a [`JSONTree`]. It is borrowed from the JSON source if possible, owned if it contains escape sequences, or shared with the other strings loaded from the same buffer.
+#[derive(Clone)]
+pub struct JSONTreeStr<'a>(Repr<'a>);
+
+#[derive(Clone)]
+enum Repr<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+    Shared { buffer: Arc<str>, start: usize, end: usize },
+}
+
+impl<'a> JSONTreeStr<'a> {
+    /// Get the string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Borrowed(s) => s,
+            Repr::Owned(s) => s.as_str(),
+            Repr::Shared {
+                buffer,
+                start,
+                end,
+            } => &buffer[*start..*end],
+        }
+    }
+
+    /// Convert to a `Cow` if it is not shared.
+    #[inline]
+    pub(crate) fn into_cow(self) -> Result<Cow<'a, str>, JSONTreeStr<'a>> {
+        match self.0 {
+            Repr::Borrowed(s) => Ok(Cow::Borrowed(s)),
+            Repr::Owned(s) => Ok(Cow::Owned(s)),
+            _ => Err(self),
+        }
+    }
+
+    /// Convert to a `JSONTreeStr` which does not borrow anything.
+    #[inline]
+    pub fn into_owned(self) -> JSONTreeStr<'static> {
+        JSONTreeStr(match self.0 {
+            Repr::Borrowed(s) => Repr::Owned(String::from(s)),
+            Repr::Owned(s) => Repr::Owned(s),
+            Repr::Shared {
+                buffer,
+                start,
+                end,
+            } => Repr::Shared {
+                buffer,
+                start,
+                end,
+            },
+        })
+    }
+
+    /// Point a string borrowed from `buffer` to the shared buffer instead.
+    fn into_shared(self, buffer: &Arc<str>) -> JSONTreeStr<'static> {
+        match self.0 {
+            Repr::Borrowed(s) => {
+                let start = (s.as_ptr() as usize).wrapping_sub(buffer.as_ptr() as usize);
+
+                match start.checked_add(s.len()) {
+                    Some(end) if end <= buffer.len() => JSONTreeStr(Repr::Shared {
+                        buffer: buffer.clone(),
+                        start,
+                        end,
+                    }),
+                    _ => JSONTreeStr(Repr::Owned(String::from(s))),
+                }
+            },
+            _ => self.into_owned(),
+        }
+    }
+}
+
+impl<'a> Deref for JSONTreeStr<'a> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> AsRef<str> for JSONTreeStr<'a> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> Borrow<str> for JSONTreeStr<'a> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a str> for JSONTreeStr<'a> {
+    #[inline]
+    fn from(s: &'a str) -> JSONTreeStr<'a> {
+        JSONTreeStr(Repr::Borrowed(s))
+    }
+}
+
+impl From<String> for JSONTreeStr<'static> {
+    #[inline]
+    fn from(s: String) -> JSONTreeStr<'static> {
+        JSONTreeStr(Repr::Owned(s))
+    }
+}
+
+impl<'a> Debug for JSONTreeStr<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<'a> Display for JSONTreeStr<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<'a, 'b> PartialEq<JSONTreeStr<'b>> for JSONTreeStr<'a> {
+    #[inline]
+    fn eq(&self, other: &JSONTreeStr<'b>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a> Eq for JSONTreeStr<'a> {}
+
+impl<'a> PartialEq<str> for JSONTreeStr<'a> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialOrd for JSONTreeStr<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for JSONTreeStr<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<'a> Hash for JSONTreeStr<'a> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<'a> Serialize for JSONTreeStr<'a> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct JSONTreeStrVisitor;
+
+impl<'de> Visitor<'de> for JSONTreeStrVisitor {
+    type Value = JSONTreeStr<'de>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<JSONTreeStr<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTreeStr::from(String::from(v)))
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<JSONTreeStr<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTreeStr::from(v))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<JSONTreeStr<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTreeStr::from(v))
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for JSONTreeStr<'a> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        deserializer.deserialize_str(JSONTreeStrVisitor)
+    }
+}
+
+/// A JSON value whose strings are borrowed from the JSON source where possible, so nested structures (e.g. plural objects or lists) do not need to be copied into a `serde_json::Value`. Object entries keep their order in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSONTree<'a> {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(JSONTreeStr<'a>),
+    Array(Vec<JSONTree<'a>>),
+    Object(Vec<(JSONTreeStr<'a>, JSONTree<'a>)>),
+}
+
+impl<'a> JSONTree<'a> {
+    /// Convert to a string slice if it is a string.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JSONTree::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Convert to an `i64` if it is an integer which fits in `i64`.
+    #[inline]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JSONTree::Number(n) => n.as_i64(),
+            _ => None,
+        }
+    }
+
+    /// Convert to a `u64` if it is an integer which fits in `u64`.
+    #[inline]
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JSONTree::Number(n) => n.as_u64(),
+            _ => None,
+        }
+    }
+
+    /// Convert to an `f64` if it is a number.
+    #[inline]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JSONTree::Number(n) => n.as_f64(),
+            _ => None,
+        }
+    }
+
+    /// Convert to a `bool` if it is a boolean.
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JSONTree::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if it is `null`.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, JSONTree::Null)
+    }
+
+    /// Get an element of an array by a position, or an entry of an object by a key.
+    #[inline]
+    pub fn get<I: JSONGetTextIndex>(&self, index: I) -> Option<&JSONTree<'a>> {
+        index.index_tree(self)
+    }
+
+    /// Convert to a `serde_json::Value`. All strings are copied.
+    pub fn to_json_value(&self) -> Value {
+        match self {
+            JSONTree::Null => Value::Null,
+            JSONTree::Bool(b) => Value::Bool(*b),
+            JSONTree::Number(n) => Value::Number(n.clone()),
+            JSONTree::String(s) => Value::String(String::from(s.as_str())),
+            JSONTree::Array(array) => {
+                Value::Array(array.iter().map(|v| v.to_json_value()).collect())
+            },
+            JSONTree::Object(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(k, v)| (String::from(k.as_str()), v.to_json_value()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Create an object from its entries. If a key appears more than once, the entry keeps the position of the first occurrence and the value of the last one, like `serde_json::Value` does.
+    pub(crate) fn from_entries(mut entries: Vec<(JSONTreeStr<'a>, JSONTree<'a>)>) -> JSONTree<'a> {
+        if entries.len() > 1 {
+            let mut order: Vec<usize> = (0..entries.len()).collect();
+
+            order.sort_by(|a, b| entries[*a].0.as_str().cmp(entries[*b].0.as_str()));
+
+            let mut removed = vec![false; entries.len()];
+            let mut duplicated = false;
+            let mut start = 0;
+
+            while start < order.len() {
+                let first = order[start];
+                let mut end = start + 1;
+
+                while end < order.len() && entries[order[end]].0 == entries[first].0 {
+                    removed[order[end]] = true;
+                    end += 1;
+                }
+
+                if end - start > 1 {
+                    // the sort is stable, so the last index of a run is the last occurrence in the source
+                    let value = mem::replace(&mut entries[order[end - 1]].1, JSONTree::Null);
+
+                    entries[first].1 = value;
+                    duplicated = true;
+                }
+
+                start = end;
+            }
+
+            if duplicated {
+                let mut removed = removed.into_iter();
+
+                entries.retain(|_| !removed.next().unwrap_or(false));
+            }
+        }
+
+        JSONTree::Object(entries)
+    }
+
+    /// Convert to a `JSONTree` which does not borrow anything.
+    pub fn into_owned(self) -> JSONTree<'static> {
+        self.map_strings(&mut JSONTreeStr::into_owned)
+    }
+
+    /// Point all strings borrowed from `buffer` to the shared buffer instead, so that they can outlive the borrow.
+    #[inline]
+    pub(crate) fn into_shared(self, buffer: &Arc<str>) -> JSONTree<'static> {
+        self.map_strings(&mut |s| s.into_shared(buffer))
+    }
+
+    fn map_strings<F: FnMut(JSONTreeStr<'a>) -> JSONTreeStr<'static>>(
+        self,
+        f: &mut F,
+    ) -> JSONTree<'static> {
+        match self {
+            JSONTree::Null => JSONTree::Null,
+            JSONTree::Bool(b) => JSONTree::Bool(b),
+            JSONTree::Number(n) => JSONTree::Number(n),
+            JSONTree::String(s) => JSONTree::String(f(s)),
+            JSONTree::Array(array) => {
+                JSONTree::Array(array.into_iter().map(|v| v.map_strings(f)).collect())
+            },
+            JSONTree::Object(entries) => JSONTree::Object(
+                entries.into_iter().map(|(k, v)| (f(k), v.map_strings(f))).collect(),
+            ),
+        }
+    }
+}
+
+impl<'a> Display for JSONTree<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            JSONTree::String(s) => f.write_str(s),
+            _ => f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?),
+        }
+    }
+}
+
+impl<'a> From<JSONTree<'a>> for Value {
+    #[inline]
+    fn from(v: JSONTree<'a>) -> Value {
+        v.to_json_value()
+    }
+}
+
+impl<'a> Serialize for JSONTree<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        match self {
+            JSONTree::Null => serializer.serialize_unit(),
+            JSONTree::Bool(b) => serializer.serialize_bool(*b),
+            JSONTree::Number(n) => n.serialize(serializer),
+            JSONTree::String(s) => serializer.serialize_str(s),
+            JSONTree::Array(array) => {
+                let mut seq = serializer.serialize_seq(Some(array.len()))?;
+
+                for v in array {
+                    seq.serialize_element(v)?;
+                }
+
+                seq.end()
+            },
+            JSONTree::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+
+                for (k, v) in entries {
+                    map.serialize_entry(k, v)?;
+                }
+
+                map.end()
+            },
+        }
+    }
+}
+
+struct JSONTreeVisitor;
+
+impl<'de> Visitor<'de> for JSONTreeVisitor {
+    type Value = JSONTree<'de>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a json value")
+    }
+
+    #[inline]
+    fn visit_bool<E>(self, v: bool) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::Bool(v))
+    }
+
+    #[inline]
+    fn visit_i64<E>(self, v: i64) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::Number(v.into()))
+    }
+
+    #[inline]
+    fn visit_u64<E>(self, v: u64) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::Number(v.into()))
+    }
+
+    #[inline]
+    fn visit_i128<E>(self, v: i128) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(DeError::custom(super::JSONGetTextValueError::IntegerOutOfRange)),
+            },
+        }
+    }
+
+    #[inline]
+    fn visit_u128<E>(self, v: u128) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(DeError::custom(super::JSONGetTextValueError::IntegerOutOfRange)),
+        }
+    }
+
+    #[inline]
+    fn visit_f64<E>(self, v: f64) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(Number::from_f64(v).map(JSONTree::Number).unwrap_or(JSONTree::Null))
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::String(JSONTreeStr::from(String::from(v))))
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::String(JSONTreeStr::from(v)))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::String(JSONTreeStr::from(v)))
+    }
+
+    #[inline]
+    fn visit_none<E>(self) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::Null)
+    }
+
+    #[inline]
+    fn visit_unit<E>(self) -> Result<JSONTree<'de>, E>
+    where
+        E: DeError, {
+        Ok(JSONTree::Null)
+    }
+
+    #[inline]
+    fn visit_seq<A>(self, mut seq: A) -> Result<JSONTree<'de>, A::Error>
+    where
+        A: SeqAccess<'de>, {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(e) = seq.next_element()? {
+            v.push(e);
+        }
+
+        Ok(JSONTree::Array(v))
+    }
+
+    #[inline]
+    fn visit_map<A>(self, mut map: A) -> Result<JSONTree<'de>, A::Error>
+    where
+        A: MapAccess<'de>, {
+        let mut v = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some(entry) = map.next_entry()? {
+            v.push(entry);
+        }
+
+        Ok(JSONTree::from_entries(v))
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for JSONTree<'a> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        deserializer.deserialize_any(JSONTreeVisitor)
+    }
+}
+
+/// Index an array by a position, or an object by a key. `JSONTree::Null` is returned if the index does not exist, like `serde_json::Value` does.
+impl<'a, I: JSONGetTextIndex> Index<I> for JSONTree<'a> {
+    type Output = JSONTree<'a>;
+
+    #[inline]
+    fn index(&self, index: I) -> &JSONTree<'a> {
+        static NULL: JSONTree<'static> = JSONTree::Null;
+
+        index.index_tree(self).unwrap_or(&NULL)
+    }
+}
+
+/// A type which can index into a JSON array (`usize`) or a JSON object (`str` and `String`). See [`JSONGetTextValue::get`].
+pub trait JSONGetTextIndex {
+    /// Index into a `serde_json::Value`.
+    fn index_value<'v>(&self, v: &'v Value) -> Option<&'v Value>;
+
+    /// Index into a `JSONTree`.
+    fn index_tree<'v, 'a>(&self, v: &'v JSONTree<'a>) -> Option<&'v JSONTree<'a>>;
+}
+
+impl JSONGetTextIndex for usize {
+    #[inline]
+    fn index_value<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        v.get(*self)
+    }
+
+    #[inline]
+    fn index_tree<'v, 'a>(&self, v: &'v JSONTree<'a>) -> Option<&'v JSONTree<'a>> {
+        match v {
+            JSONTree::Array(array) => array.get(*self),
+            _ => None,
+        }
+    }
+}
+
+impl JSONGetTextIndex for str {
+    #[inline]
+    fn index_value<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        v.get(self)
+    }
+
+    #[inline]
+    fn index_tree<'v, 'a>(&self, v: &'v JSONTree<'a>) -> Option<&'v JSONTree<'a>> {
+        match v {
+            JSONTree::Object(entries) => {
+                entries.iter().find(|(k, _)| k.as_str() == self).map(|(_, v)| v)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl JSONGetTextIndex for String {
+    #[inline]
+    fn index_value<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        self.as_str().index_value(v)
+    }
+
+    #[inline]
+    fn index_tree<'v, 'a>(&self, v: &'v JSONTree<'a>) -> Option<&'v JSONTree<'a>> {
+        self.as_str().index_tree(v)
+    }
+}
+
+impl<T: JSONGetTextIndex + ?Sized> JSONGetTextIndex for &T {
+    #[inline]
+    fn index_value<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        (**self).index_value(v)
+    }
+
+    #[inline]
+    fn index_tree<'v, 'a>(&self, v: &'v JSONTree<'a>) -> Option<&'v JSONTree<'a>> {
+        (**self).index_tree(v)
+    }
+}
+
+/// Parse a JSON object whose strings are shared with `buffer` instead of being copied one by one.
+pub(crate) fn parse_shared_json_map(
+    buffer: &Arc<str>,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, serde_json::Error> {
+    let map: HashMap<String, JSONTree> = serde_json::from_str(buffer)?;
+
+    Ok(map.into_iter().map(|(k, v)| (k, JSONGetTextValue::Tree(v.into_shared(buffer)))).collect())
+}
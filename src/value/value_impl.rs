@@ -6,11 +6,24 @@ extern crate rocket;
 #[cfg(feature = "rocket")]
 use std::io::Cursor;
 use std::{
+    borrow::Cow,
     convert::TryFrom,
     fmt::{self, Display, Formatter},
+    io::{self, Write},
+    ops::Index,
     str::FromStr,
 };
 
+#[cfg(feature = "actix")]
+use actix_web::{
+    body::BoxBody, http::header::ContentType, HttpRequest, HttpResponse,
+    Responder as ActixResponder,
+};
+#[cfg(feature = "axum")]
+use axum::{
+    http::header,
+    response::{IntoResponse, Response as AxumResponse},
+};
 #[cfg(feature = "rocket")]
 use rocket::form::{self, FromFormField, ValueField};
 #[cfg(feature = "rocket")]
@@ -18,31 +31,53 @@ use rocket::request::{FromParam, Request};
 #[cfg(feature = "rocket")]
 use rocket::response::{self, Responder, Response};
 use serde::{
-    de::{Error as DeError, MapAccess, SeqAccess, Visitor},
+    de::{value::BorrowedStrDeserializer, DeserializeOwned},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use super::JSONGetTextValueError;
-use crate::serde_json::{self, to_value, Map, Value};
+use super::{
+    EscapeFormatter, JSONEscapeOptions, JSONGetTextArrayIter, JSONGetTextIndex,
+    JSONGetTextObjectIter, JSONGetTextValueError, JSONTree,
+};
+use crate::serde_json::{self, to_value, value::Index as JSONIndex, Map, Value};
 
 /// Represents any valid JSON value. Reference can also be wrapped.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Values are compared and hashed by their JSON content, regardless of how they are stored, so they can be used as map keys. Object entries are compared in the order of their keys.
+#[derive(Debug, Clone)]
 pub enum JSONGetTextValue<'a> {
-    Str(&'a str),
+    /// A string, which is either borrowed or owned (e.g. a formatted text).
+    Str(Cow<'a, str>),
     JSONValue(Value),
     JSONValueRef(&'a Value),
+    /// A JSON value whose strings are borrowed (or shared), e.g. a plural object loaded by [`JSONGetTextBuilder`](crate::JSONGetTextBuilder).
+    Tree(JSONTree<'a>),
+    TreeRef(&'a JSONTree<'a>),
+}
+
+/// A borrowed view of a `JSONGetTextValue` instance, so that the accessors only need to handle three cases.
+#[derive(Clone, Copy)]
+pub(super) enum Node<'v> {
+    Str(&'v str),
+    Value(&'v Value),
+    Tree(&'v JSONTree<'v>),
 }
 
 impl<'a> JSONGetTextValue<'a> {
     #[inline]
     #[allow(clippy::should_implement_trait)]
     pub fn from_str<S: AsRef<str> + ?Sized>(s: &'a S) -> JSONGetTextValue<'a> {
-        JSONGetTextValue::Str(s.as_ref())
+        JSONGetTextValue::Str(Cow::Borrowed(s.as_ref()))
     }
 
     #[inline]
     pub fn from_string<S: Into<String>>(s: S) -> JSONGetTextValue<'static> {
-        JSONGetTextValue::JSONValue(Value::String(s.into()))
+        JSONGetTextValue::Str(Cow::Owned(s.into()))
+    }
+
+    #[inline]
+    pub fn from_cow(s: Cow<'a, str>) -> JSONGetTextValue<'a> {
+        JSONGetTextValue::Str(s)
     }
 
     #[inline]
@@ -141,6 +176,17 @@ impl<'a> JSONGetTextValue<'a> {
         JSONGetTextValue::JSONValueRef(v)
     }
 
+    #[inline]
+    pub fn from_json_tree(v: JSONTree<'a>) -> JSONGetTextValue<'a> {
+        match v {
+            JSONTree::String(s) => match s.into_cow() {
+                Ok(s) => JSONGetTextValue::Str(s),
+                Err(s) => JSONGetTextValue::Tree(JSONTree::String(s)),
+            },
+            _ => JSONGetTextValue::Tree(v),
+        }
+    }
+
     #[inline]
     pub fn from_serializable<T: Serialize>(
         v: T,
@@ -155,37 +201,30 @@ impl<'a> JSONGetTextValue<'a> {
 }
 
 impl<'a> JSONGetTextValue<'a> {
-    /// Convert to a string for JSON format.
+    /// Convert to a string for JSON format. Strings are escaped as RFC 8259 requires.
+    #[inline]
     pub fn to_json_string(&self) -> String {
-        match self {
-            JSONGetTextValue::Str(s) => {
-                let mut string = String::with_capacity(s.len() + 2);
-                string.push('"');
-
-                let mut from = 0;
-
-                for (i, c) in s.char_indices() {
-                    let esc = c.escape_debug();
+        self.to_json_string_with_options(&JSONEscapeOptions::default())
+    }
 
-                    if esc.len() != 1 {
-                        string.push_str(&s[from..i]);
+    /// Convert to a string for JSON format with extra escapes, e.g. [`JSONEscapeOptions::html`] for embedding in a `<script>` element.
+    #[inline]
+    pub fn to_json_string_with_options(&self, options: &JSONEscapeOptions) -> String {
+        let mut bytes = Vec::new();
 
-                        for c in esc {
-                            string.push(c);
-                        }
+        self.write_json(&mut bytes, options).unwrap();
 
-                        from = i + c.len_utf8();
-                    }
-                }
+        // the JSON serializer only writes UTF-8
+        String::from_utf8(bytes).unwrap()
+    }
 
-                string.push_str(&s[from..]);
-                string.push('"');
+    /// Write this value in JSON format to a writer without building a string first.
+    pub fn write_json<W: Write>(&self, writer: W, options: &JSONEscapeOptions) -> io::Result<()> {
+        let mut serializer = serde_json::Serializer::with_formatter(writer, EscapeFormatter {
+            options: *options,
+        });
 
-                string
-            },
-            JSONGetTextValue::JSONValue(v) => v.to_string(),
-            JSONGetTextValue::JSONValueRef(v) => v.to_string(),
-        }
+        self.serialize(&mut serializer).map_err(io::Error::from)
     }
 
     #[deprecated(since = "3.2.0", note = "Please use the `to_json_string` function instead")]
@@ -198,26 +237,192 @@ impl<'a> JSONGetTextValue<'a> {
     /// Convert to a string slice if it is possible (if it is a string).
     #[inline]
     pub fn as_str(&self) -> Option<&str> {
+        match self.node() {
+            Node::Str(s) => Some(s),
+            Node::Value(v) => v.as_str(),
+            Node::Tree(v) => v.as_str(),
+        }
+    }
+
+    /// Convert to an `i64` if it is an integer which fits in `i64`.
+    #[inline]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_i64(),
+            Node::Tree(v) => v.as_i64(),
+        }
+    }
+
+    /// Convert to a `u64` if it is an integer which fits in `u64`.
+    #[inline]
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_u64(),
+            Node::Tree(v) => v.as_u64(),
+        }
+    }
+
+    /// Convert to an `f64` if it is a number.
+    #[inline]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_f64(),
+            Node::Tree(v) => v.as_f64(),
+        }
+    }
+
+    /// Convert to a `bool` if it is a boolean.
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_bool(),
+            Node::Tree(v) => v.as_bool(),
+        }
+    }
+
+    /// Get the elements if it is an array stored as a `serde_json::Value`. Arrays loaded as [`JSONTree`] values are iterated with [`iter_array`](Self::iter_array).
+    #[inline]
+    pub fn as_array(&self) -> Option<&Vec<Value>> {
+        match self.node() {
+            Node::Value(v) => v.as_array(),
+            _ => None,
+        }
+    }
+
+    /// Get the entries if it is an object stored as a `serde_json::Value`. Objects loaded as [`JSONTree`] values are iterated with [`iter_object`](Self::iter_object).
+    #[inline]
+    pub fn as_object(&self) -> Option<&Map<String, Value>> {
+        match self.node() {
+            Node::Value(v) => v.as_object(),
+            _ => None,
+        }
+    }
+
+    /// Get the tree if it is stored as a [`JSONTree`], which can be indexed by `[]`.
+    #[inline]
+    pub fn as_tree(&self) -> Option<&JSONTree<'a>> {
         match self {
-            JSONGetTextValue::Str(s) => Some(s),
-            JSONGetTextValue::JSONValue(v) => match v {
-                Value::String(s) => Some(s),
-                _ => None,
-            },
-            JSONGetTextValue::JSONValueRef(v) => match v {
-                Value::String(s) => Some(s),
-                _ => None,
+            JSONGetTextValue::Tree(v) => Some(v),
+            JSONGetTextValue::TreeRef(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Iterate over the borrowed elements if it is an array, however it is stored.
+    #[inline]
+    pub fn iter_array(&self) -> Option<JSONGetTextArrayIter<'_>> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_array().map(|array| JSONGetTextArrayIter::from_value(array)),
+            Node::Tree(JSONTree::Array(array)) => Some(JSONGetTextArrayIter::from_tree(array)),
+            Node::Tree(_) => None,
+        }
+    }
+
+    /// Iterate over the borrowed entries if it is an object, however it is stored.
+    #[inline]
+    pub fn iter_object(&self) -> Option<JSONGetTextObjectIter<'_>> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => v.as_object().map(JSONGetTextObjectIter::from_value),
+            Node::Tree(JSONTree::Object(entries)) => {
+                Some(JSONGetTextObjectIter::from_tree(entries))
             },
+            Node::Tree(_) => None,
+        }
+    }
+
+    /// Returns `true` if it is `null`.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        match self.node() {
+            Node::Str(_) => false,
+            Node::Value(v) => v.is_null(),
+            Node::Tree(v) => v.is_null(),
+        }
+    }
+
+    /// Get an element of an array by a position, or an entry of an object by a key.
+    #[inline]
+    pub fn get<I: JSONGetTextIndex>(&self, index: I) -> Option<JSONGetTextValue<'_>> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => index.index_value(v).map(JSONGetTextValue::JSONValueRef),
+            Node::Tree(v) => index.index_tree(v).map(JSONGetTextValue::TreeRef),
+        }
+    }
+
+    /// Deserialize this value into any deserializable type, e.g. a `Vec<String>` of weekday names.
+    pub fn deserialize_into<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        match self.node() {
+            Node::Str(s) => T::deserialize(BorrowedStrDeserializer::<serde_json::Error>::new(s)),
+            Node::Value(v) => T::deserialize(v),
+            Node::Tree(v) => T::deserialize(v.to_json_value()),
+        }
+    }
+
+    #[inline]
+    pub(super) fn node(&self) -> Node<'_> {
+        match self {
+            JSONGetTextValue::Str(s) => Node::Str(s),
+            JSONGetTextValue::JSONValue(v) => Node::Value(v),
+            JSONGetTextValue::JSONValueRef(v) => Node::Value(v),
+            JSONGetTextValue::Tree(v) => Node::Tree(v),
+            JSONGetTextValue::TreeRef(v) => Node::Tree(v),
+        }
+    }
+
+    /// Get the inner value as a `serde_json::Value` if this `JSONGetTextValue` instance is not a string slice. Trees are converted.
+    #[allow(dead_code)]
+    #[inline]
+    pub(crate) fn to_json_value(&self) -> Option<Cow<'_, Value>> {
+        match self.node() {
+            Node::Str(_) => None,
+            Node::Value(v) => Some(Cow::Borrowed(v)),
+            Node::Tree(v) => Some(Cow::Owned(v.to_json_value())),
         }
     }
 
     /// Clone the reference of this `JSONGetTextValue` instance.
     #[inline]
-    pub fn clone_borrowed(&self) -> JSONGetTextValue {
+    pub fn clone_borrowed(&self) -> JSONGetTextValue<'_> {
         match self {
-            JSONGetTextValue::Str(s) => JSONGetTextValue::Str(s),
+            JSONGetTextValue::Str(s) => JSONGetTextValue::Str(Cow::Borrowed(s)),
             JSONGetTextValue::JSONValue(v) => JSONGetTextValue::JSONValueRef(v),
             JSONGetTextValue::JSONValueRef(v) => JSONGetTextValue::JSONValueRef(v),
+            JSONGetTextValue::Tree(v) => JSONGetTextValue::TreeRef(v),
+            JSONGetTextValue::TreeRef(v) => JSONGetTextValue::TreeRef(v),
+        }
+    }
+
+    /// Convert to a `JSONGetTextValue` which does not borrow anything, e.g. to move it into a spawned task or a cache. Borrowed strings and JSON values are cloned.
+    #[inline]
+    pub fn into_owned(self) -> JSONGetTextValue<'static> {
+        match self {
+            JSONGetTextValue::Str(s) => JSONGetTextValue::Str(Cow::Owned(s.into_owned())),
+            JSONGetTextValue::JSONValue(v) => JSONGetTextValue::JSONValue(v),
+            JSONGetTextValue::JSONValueRef(v) => JSONGetTextValue::JSONValue(v.clone()),
+            JSONGetTextValue::Tree(v) => JSONGetTextValue::Tree(v.into_owned()),
+            JSONGetTextValue::TreeRef(v) => JSONGetTextValue::Tree(v.clone().into_owned()),
+        }
+    }
+}
+
+/// Index an array by a position, or an object by a key. `Value::Null` is returned if the index does not exist, like `serde_json::Value` does. Only values stored as a `serde_json::Value` can be indexed this way; a [`JSONTree`] is indexed by [`get`](JSONGetTextValue::get) or through [`as_tree`](JSONGetTextValue::as_tree).
+impl<'a, I: JSONIndex> Index<I> for JSONGetTextValue<'a> {
+    type Output = Value;
+
+    #[inline]
+    fn index(&self, index: I) -> &Value {
+        static NULL: Value = Value::Null;
+
+        match self.node() {
+            Node::Value(v) => v.get(index).unwrap_or(&NULL),
+            _ => &NULL,
         }
     }
 }
@@ -225,33 +430,21 @@ impl<'a> JSONGetTextValue<'a> {
 impl<'a> PartialEq<JSONGetTextValue<'a>> for str {
     #[inline]
     fn eq(&self, other: &JSONGetTextValue) -> bool {
-        match other {
-            JSONGetTextValue::Str(s) => s.eq(&self),
-            JSONGetTextValue::JSONValue(v) => v.eq(&self),
-            JSONGetTextValue::JSONValueRef(v) => v.eq(&self),
-        }
+        other.as_str() == Some(self)
     }
 }
 
 impl<'a> PartialEq<JSONGetTextValue<'a>> for &'a str {
     #[inline]
     fn eq(&self, other: &JSONGetTextValue) -> bool {
-        match other {
-            JSONGetTextValue::Str(s) => s.eq(self),
-            JSONGetTextValue::JSONValue(v) => v.eq(self),
-            JSONGetTextValue::JSONValueRef(v) => v.eq(self),
-        }
+        other.as_str() == Some(*self)
     }
 }
 
 impl<'a> PartialEq<str> for JSONGetTextValue<'a> {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        match self {
-            JSONGetTextValue::Str(s) => s.eq(&other),
-            JSONGetTextValue::JSONValue(v) => v.eq(&other),
-            JSONGetTextValue::JSONValueRef(v) => v.eq(&other),
-        }
+        self.as_str() == Some(other)
     }
 }
 
@@ -268,6 +461,8 @@ impl<'a> Display for JSONGetTextValue<'a> {
                 Some(s) => s.fmt(f),
                 None => v.fmt(f),
             },
+            JSONGetTextValue::Tree(v) => v.fmt(f),
+            JSONGetTextValue::TreeRef(v) => v.fmt(f),
         }
     }
 }
@@ -279,6 +474,13 @@ impl<'a> From<&'a str> for JSONGetTextValue<'a> {
     }
 }
 
+impl<'a> From<Cow<'a, str>> for JSONGetTextValue<'a> {
+    #[inline]
+    fn from(v: Cow<'a, str>) -> JSONGetTextValue<'a> {
+        JSONGetTextValue::from_cow(v)
+    }
+}
+
 impl From<String> for JSONGetTextValue<'static> {
     #[inline]
     fn from(v: String) -> JSONGetTextValue<'static> {
@@ -409,6 +611,13 @@ impl<'a> From<&'a Value> for JSONGetTextValue<'a> {
     }
 }
 
+impl<'a> From<JSONTree<'a>> for JSONGetTextValue<'a> {
+    #[inline]
+    fn from(v: JSONTree<'a>) -> JSONGetTextValue<'a> {
+        JSONGetTextValue::from_json_tree(v)
+    }
+}
+
 impl FromStr for JSONGetTextValue<'static> {
     type Err = ();
 
@@ -438,129 +647,19 @@ impl<'a> Serialize for JSONGetTextValue<'a> {
             JSONGetTextValue::Str(s) => s.serialize(serializer),
             JSONGetTextValue::JSONValue(v) => v.serialize(serializer),
             JSONGetTextValue::JSONValueRef(v) => v.serialize(serializer),
+            JSONGetTextValue::Tree(v) => v.serialize(serializer),
+            JSONGetTextValue::TreeRef(v) => v.serialize(serializer),
         }
     }
 }
 
-struct JSONGetTextValueVisitor;
-
-impl<'de> Visitor<'de> for JSONGetTextValueVisitor {
-    type Value = JSONGetTextValue<'de>;
-
-    serde::serde_if_integer128! {
-        #[inline]
-        fn visit_i128<E>(self, v: i128) -> Result<JSONGetTextValue<'static>, E> where E: DeError {
-            JSONGetTextValue::from_i128(v).map_err(DeError::custom)
-        }
-    }
-
-    serde::serde_if_integer128! {
-        #[inline]
-        fn visit_u128<E>(self, v: u128) -> Result<JSONGetTextValue<'static>, E> where E: DeError {
-            JSONGetTextValue::from_u128(v).map_err(DeError::custom)
-        }
-    }
-
-    #[inline]
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a json value")
-    }
-
-    #[inline]
-    fn visit_bool<E>(self, v: bool) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_bool(v))
-    }
-
-    #[inline]
-    fn visit_i64<E>(self, v: i64) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_i64(v))
-    }
-
-    #[inline]
-    fn visit_u64<E>(self, v: u64) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_u64(v))
-    }
-
-    #[inline]
-    fn visit_f64<E>(self, v: f64) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_f64(v))
-    }
-
-    #[inline]
-    fn visit_str<E>(self, v: &str) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_string(v.to_string()))
-    }
-
-    #[inline]
-    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<JSONGetTextValue<'de>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_str(v))
-    }
-
-    #[inline]
-    fn visit_string<E>(self, v: String) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::from_string(v))
-    }
-
-    #[inline]
-    fn visit_none<E>(self) -> Result<JSONGetTextValue<'static>, E>
-    where
-        E: DeError, {
-        Ok(JSONGetTextValue::null())
-    }
-
-    #[inline]
-    fn visit_seq<A>(self, mut seq: A) -> Result<JSONGetTextValue<'static>, A::Error>
-    where
-        A: SeqAccess<'de>, {
-        let mut v = match seq.size_hint() {
-            Some(size) => Vec::with_capacity(size),
-            None => Vec::new(),
-        };
-
-        while let Some(e) = seq.next_element()? {
-            v.push(e);
-        }
-
-        Ok(JSONGetTextValue::from_json_value(Value::Array(v)))
-    }
-
-    #[inline]
-    fn visit_map<A>(self, mut map: A) -> Result<JSONGetTextValue<'static>, A::Error>
-    where
-        A: MapAccess<'de>, {
-        let mut v = match map.size_hint() {
-            Some(size) => Map::with_capacity(size),
-            None => Map::new(),
-        };
-
-        while let Some((k, e)) = map.next_entry()? {
-            v.insert(k, e);
-        }
-
-        Ok(JSONGetTextValue::from_json_value(Value::Object(v)))
-    }
-}
-
-impl<'de> Deserialize<'de> for JSONGetTextValue<'de> {
+impl<'de: 'a, 'a> Deserialize<'de> for JSONGetTextValue<'a> {
+    /// Strings are borrowed from the deserializer if possible. Objects and arrays become [`JSONTree`] values, whose strings are also borrowed if possible.
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>, {
-        deserializer.deserialize_str(JSONGetTextValueVisitor)
+        JSONTree::deserialize(deserializer).map(JSONGetTextValue::from_json_tree)
     }
 }
 
@@ -582,6 +681,24 @@ impl<'r, 'o: 'r> Responder<'r, 'o> for JSONGetTextValue<'o> {
     }
 }
 
+#[cfg(feature = "actix")]
+impl<'a> ActixResponder for JSONGetTextValue<'a> {
+    type Body = BoxBody;
+
+    #[inline]
+    fn respond_to(self, _: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok().content_type(ContentType::json()).body(self.to_json_string())
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<'a> IntoResponse for JSONGetTextValue<'a> {
+    #[inline]
+    fn into_response(self) -> AxumResponse {
+        ([(header::CONTENT_TYPE, "application/json")], self.to_json_string()).into_response()
+    }
+}
+
 #[cfg(feature = "rocket")]
 impl<'a> FromParam<'a> for JSONGetTextValue<'a> {
     type Error = JSONGetTextValueError;
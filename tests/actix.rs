@@ -0,0 +1,118 @@
+#![cfg(feature = "actix")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use json_gettext::{
+    HTMLText, JSONGetTextData, JSONGetTextValue, LocaleConfig, LocaleSource, Translator,
+};
+
+async fn hello(translator: Translator) -> String {
+    format!("{} {:?}", translator.t("hello").unwrap(), translator.get_source())
+}
+
+async fn value(translator: Translator) -> JSONGetTextValue<'static> {
+    translator.t("hello").unwrap().into_owned()
+}
+
+async fn html(translator: Translator) -> HTMLText {
+    translator.t_html("hello", &[]).unwrap()
+}
+
+fn data() -> JSONGetTextData {
+    JSONGetTextData::new(
+        static_json_gettext_build_handle!(
+            "en_US";
+            "en_US" => "langs/en_US.json",
+            "zh_TW" => "langs/zh_TW.json",
+        )
+        .unwrap(),
+    )
+}
+
+#[actix_web::test]
+async fn extractor() {
+    let app = init_service(
+        App::new().app_data(web::Data::new(data())).route("/hello", web::get().to(hello)),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri("/hello").to_request()).await;
+
+    assert_eq!("Hello, world! None", read_body(response).await);
+
+    let response =
+        call_service(&app, TestRequest::get().uri("/hello?lang=zh-tw").to_request()).await;
+
+    assert_eq!("哈囉，世界！ Some(Query(\"lang\"))", read_body(response).await);
+
+    let response = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/hello")
+            .insert_header((header::COOKIE, "a=b; lang=zh_TW"))
+            .insert_header((header::ACCEPT_LANGUAGE, "en-US"))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!("哈囉，世界！ Some(Cookie)", read_body(response).await);
+
+    let response = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/hello")
+            .insert_header((header::ACCEPT_LANGUAGE, "fr, zh-TW;q=0.8, en-US;q=0.5"))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!("哈囉，世界！ Some(AcceptLanguage)", read_body(response).await);
+
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(
+                data().with_config(LocaleConfig::new(vec![LocaleSource::PathSegment(0)])),
+            ))
+            .route("/{lang}/hello", web::get().to(hello)),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri("/zh_TW/hello").to_request()).await;
+
+    assert_eq!("哈囉，世界！ Some(PathSegment(0))", read_body(response).await);
+}
+
+#[actix_web::test]
+async fn responders() {
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(data()))
+            .route("/value", web::get().to(value))
+            .route("/html", web::get().to(html))
+            .route("/hello", web::get().to(hello)),
+    )
+    .await;
+
+    let response =
+        call_service(&app, TestRequest::get().uri("/value?lang=zh_TW").to_request()).await;
+
+    assert_eq!("application/json", response.headers().get(header::CONTENT_TYPE).unwrap());
+    assert_eq!("\"哈囉，世界！\"", read_body(response).await);
+
+    let response = call_service(&app, TestRequest::get().uri("/html").to_request()).await;
+
+    assert_eq!("text/html; charset=utf-8", response.headers().get(header::CONTENT_TYPE).unwrap());
+    assert_eq!("Hello, world!", read_body(response).await);
+
+    let app = init_service(App::new().route("/hello", web::get().to(hello))).await;
+
+    let response = call_service(&app, TestRequest::get().uri("/hello").to_request()).await;
+
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "android")]
+
+use json_gettext::{parse_android_strings_xml, JSONGetText};
+
+const STRINGS_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources>
+    <string name="hello">Hello, world!</string>
+    <string name="quote">Don\'t say \"no\" &amp; <b>smile</b></string>
+    <string name="spaces">"  two  spaces  "</string>
+    <string-array name="weekdays">
+        <item>Monday</item>
+        <item>Tuesday</item>
+    </string-array>
+    <plurals name="apples">
+        <item quantity="one">One apple</item>
+        <item quantity="other">Many apples</item>
+    </plurals>
+</resources>
+"#;
+
+#[test]
+fn import() {
+    let map = parse_android_strings_xml(STRINGS_XML).unwrap();
+
+    assert_eq!("Hello, world!", map.get("hello").unwrap());
+    assert_eq!("Don't say \"no\" & <b>smile</b>", map.get("quote").unwrap());
+    assert_eq!("  two  spaces  ", map.get("spaces").unwrap());
+    assert_eq!(r#"["Monday","Tuesday"]"#, map.get("weekdays").unwrap().to_json_string());
+    assert_eq!(
+        r#"{"one":"One apple","other":"Many apples"}"#,
+        map.get("apples").unwrap().to_json_string()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_android_strings_xml("en_US", STRINGS_XML).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let xml = ctx.to_android_strings_xml("en_US").unwrap();
+
+    assert_eq!(
+        &parse_android_strings_xml(STRINGS_XML).unwrap(),
+        &parse_android_strings_xml(xml).unwrap()
+    );
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "apple")]
+
+use json_gettext::{parse_apple_strings, parse_apple_stringsdict, JSONGetText};
+
+const STRINGS: &str = r#"/* Greeting */
+"hello" = "Hello, world!";
+// Quotes
+"quote" = "Say \"hi\"\nplease";
+rust = "Rust!";
+"#;
+
+const STRINGSDICT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>apples</key>
+    <dict>
+        <key>NSStringLocalizedFormatKey</key>
+        <string>%#@count@</string>
+        <key>count</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>d</string>
+            <key>one</key>
+            <string>%d apple</string>
+            <key>other</key>
+            <string>%d apples</string>
+        </dict>
+    </dict>
+</dict>
+</plist>
+"#;
+
+#[test]
+fn import() {
+    let map = parse_apple_strings(STRINGS).unwrap();
+
+    assert_eq!("Hello, world!", map.get("hello").unwrap());
+    assert_eq!("Say \"hi\"\nplease", map.get("quote").unwrap());
+    assert_eq!("Rust!", map.get("rust").unwrap());
+
+    let map = parse_apple_stringsdict(STRINGSDICT).unwrap();
+
+    assert_eq!(
+        r#"{"NSStringLocalizedFormatKey":"%#@count@","one":"%d apple","other":"%d apples"}"#,
+        map.get("apples").unwrap().to_json_string()
+    );
+}
+
+const STRINGSDICT_VALUE_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>files</key>
+    <dict>
+        <key>NSStringLocalizedFormatKey</key>
+        <string>%#@value@</string>
+        <key>value</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>lu</string>
+            <key>one</key>
+            <string>%lu file</string>
+            <key>other</key>
+            <string>%lu files</string>
+        </dict>
+    </dict>
+    <key>users</key>
+    <dict>
+        <key>NSStringLocalizedFormatKey</key>
+        <string>%#@users@</string>
+        <key>users</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>@</string>
+            <key>one</key>
+            <string>%@ user</string>
+            <key>other</key>
+            <string>%@ users</string>
+        </dict>
+    </dict>
+</dict>
+</plist>
+"#;
+
+#[test]
+fn value_types() {
+    let map = parse_apple_stringsdict(STRINGSDICT_VALUE_TYPES).unwrap();
+
+    assert_eq!(
+        r#"{"NSStringFormatValueTypeKey":"lu","one":"%lu file","other":"%lu files"}"#,
+        map.get("files").unwrap().to_json_string()
+    );
+    assert_eq!(
+        r#"{"NSStringFormatValueTypeKey":"@","NSStringLocalizedFormatKey":"%#@users@","one":"%@ user","other":"%@ users"}"#,
+        map.get("users").unwrap().to_json_string()
+    );
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_apple_strings("en_US", "", Some(STRINGSDICT_VALUE_TYPES)).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let stringsdict = ctx.to_apple_stringsdict("en_US").unwrap();
+
+    assert!(stringsdict.contains("<string>%#@users@</string>"));
+    assert!(stringsdict.contains("<string>lu</string>"));
+    assert!(stringsdict.contains("<string>@</string>"));
+    assert_eq!(
+        parse_apple_stringsdict(STRINGSDICT_VALUE_TYPES).unwrap(),
+        parse_apple_stringsdict(stringsdict).unwrap()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_apple_strings("en_US", STRINGS, Some(STRINGSDICT)).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let strings = ctx.to_apple_strings("en_US").unwrap();
+    let stringsdict = ctx.to_apple_stringsdict("en_US").unwrap();
+
+    assert_eq!(parse_apple_strings(STRINGS).unwrap(), parse_apple_strings(strings).unwrap());
+    assert_eq!(
+        parse_apple_stringsdict(STRINGSDICT).unwrap(),
+        parse_apple_stringsdict(stringsdict).unwrap()
+    );
+}
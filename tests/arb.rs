@@ -0,0 +1,64 @@
+use json_gettext::{parse_arb, parse_arb_descriptions, ARBMetadata, JSONGetText};
+
+const APP_EN_ARB: &str = r#"{
+    "@@locale": "en",
+    "hello": "Hello, world!",
+    "@hello": {
+        "description": "The greeting on the home page"
+    },
+    "welcome": "Welcome, {name}!",
+    "@welcome": {
+        "placeholders": {
+            "name": {}
+        }
+    }
+}"#;
+
+const APP_ZH_ARB: &str = r#"{
+    "@@locale": "zh_TW",
+    "hello": "哈囉，世界！"
+}"#;
+
+#[test]
+fn strip_metadata() {
+    let map = parse_arb(APP_EN_ARB).unwrap();
+
+    assert_eq!(2, map.len());
+    assert_eq!("Hello, world!", map.get("hello").unwrap());
+    assert_eq!("Welcome, {name}!", map.get("welcome").unwrap());
+
+    let descriptions = parse_arb_descriptions(APP_EN_ARB).unwrap();
+
+    assert_eq!(1, descriptions.len());
+    assert_eq!("The greeting on the home page", descriptions.get("hello").unwrap());
+}
+
+#[test]
+fn retain_descriptions() {
+    let mut builder = JSONGetText::build("en");
+
+    builder.add_arb("en", APP_EN_ARB, ARBMetadata::Description).unwrap();
+    builder.add_arb("zh_TW", APP_ZH_ARB, ARBMetadata::Description).unwrap();
+
+    // a duplicated key does not override the descriptions
+    assert!(builder
+        .add_arb(
+            "en",
+            r#"{"hello": "Hi", "@hello": {"description": "Other"}}"#,
+            ARBMetadata::Description
+        )
+        .is_err());
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Some("The greeting on the home page"), ctx.get_description("hello"));
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert!(ctx.get_description("welcome").is_none());
+    assert!(ctx.get_text("@hello").is_none());
+
+    let mut builder = JSONGetText::build("en");
+
+    builder.add_arb("en", APP_EN_ARB, ARBMetadata::Strip).unwrap();
+
+    assert!(builder.build().unwrap().get_descriptions().is_empty());
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "axum")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::sync::Arc;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    response::Response,
+    routing::get,
+    Router,
+};
+use json_gettext::{
+    HTMLText, JSONGetText, JSONGetTextLayer, JSONGetTextValue, LocaleConfig, LocaleSource,
+    Translator,
+};
+use tower::ServiceExt;
+
+async fn hello(translator: Translator) -> String {
+    format!("{} {:?}", translator.t("hello").unwrap(), translator.get_source())
+}
+
+async fn value(translator: Translator) -> JSONGetTextValue<'static> {
+    translator.t("hello").unwrap().into_owned()
+}
+
+async fn html(translator: Translator) -> HTMLText {
+    translator.t_html("hello", &[]).unwrap()
+}
+
+fn ctx() -> Arc<JSONGetText<'static>> {
+    Arc::new(
+        static_json_gettext_build!(
+            "en_US";
+            "en_US" => "langs/en_US.json",
+            "zh_TW" => "langs/zh_TW.json"
+        )
+        .unwrap(),
+    )
+}
+
+async fn body_string(response: Response) -> String {
+    String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn layer_and_extractor() {
+    let app = Router::new().route("/hello", get(hello)).layer(JSONGetTextLayer::new(ctx()));
+
+    let response =
+        app.clone().oneshot(Request::get("/hello").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!("Hello, world! None", body_string(response).await);
+
+    let response = app
+        .clone()
+        .oneshot(Request::get("/hello?lang=zh-tw").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!("哈囉，世界！ Some(Query(\"lang\"))", body_string(response).await);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::get("/hello")
+                .header(header::COOKIE, "a=b; lang=zh_TW")
+                .header(header::ACCEPT_LANGUAGE, "en-US")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!("哈囉，世界！ Some(Cookie)", body_string(response).await);
+
+    let response = app
+        .oneshot(
+            Request::get("/hello")
+                .header(header::ACCEPT_LANGUAGE, "fr, zh-TW;q=0.8, en-US;q=0.5")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!("哈囉，世界！ Some(AcceptLanguage)", body_string(response).await);
+
+    let app = Router::new().route("/{lang}/hello", get(hello)).layer(
+        JSONGetTextLayer::new(ctx())
+            .with_config(LocaleConfig::new(vec![LocaleSource::PathSegment(0)])),
+    );
+
+    let response =
+        app.oneshot(Request::get("/zh_TW/hello").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!("哈囉，世界！ Some(PathSegment(0))", body_string(response).await);
+}
+
+#[tokio::test]
+async fn responses() {
+    let app = Router::new()
+        .route("/value", get(value))
+        .route("/html", get(html))
+        .layer(JSONGetTextLayer::new(ctx()));
+
+    let response = app
+        .clone()
+        .oneshot(Request::get("/value?lang=zh_TW").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!("application/json", response.headers()[header::CONTENT_TYPE]);
+    assert_eq!("\"哈囉，世界！\"", body_string(response).await);
+
+    let response = app.oneshot(Request::get("/html").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!("text/html; charset=utf-8", response.headers()[header::CONTENT_TYPE]);
+    assert_eq!("Hello, world!", body_string(response).await);
+
+    let app = Router::new().route("/hello", get(hello));
+
+    let response = app.oneshot(Request::get("/hello").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+}
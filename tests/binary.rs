@@ -0,0 +1,116 @@
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{
+    ARBMetadata, JSONGetText, JSONGetTextBinary, JSONGetTextBuildError, JSONGetTextFormatError,
+};
+
+#[test]
+fn round_trip() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let bytes = ctx.to_binary();
+
+    assert_eq!(bytes, ctx.to_binary());
+
+    let ctx = JSONGetText::from_binary(&bytes).unwrap();
+
+    assert_eq!("en_US", ctx.get_default_key());
+    assert_eq!("Hello, world!", get_text!(ctx, "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", get_text!(ctx, "zh_TW", "rust").unwrap());
+}
+
+#[test]
+fn corrupted() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+    )
+    .unwrap();
+
+    let mut bytes = ctx.to_binary();
+
+    let last = bytes.len() - 1;
+
+    bytes[last] ^= 0xFF;
+
+    assert!(matches!(
+        JSONGetText::<String>::from_binary(&bytes),
+        Err(JSONGetTextBuildError::FormatError(JSONGetTextFormatError::ChecksumMismatch))
+    ));
+
+    assert!(matches!(
+        JSONGetText::<String>::from_binary(&bytes[..last]),
+        Err(JSONGetTextBuildError::FormatError(JSONGetTextFormatError::InvalidFormat(_)))
+    ));
+}
+
+const APP_EN_ARB: &str = r#"{
+    "hello": "Hello, world!",
+    "@hello": {
+        "description": "A greeting"
+    },
+    "rust": "Rust!",
+    "apples": {"one": "{count} apple", "other": "{count} apples"}
+}"#;
+
+#[test]
+fn metadata_and_trees() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_arb("en_US", APP_EN_ARB, ARBMetadata::Description).unwrap();
+    builder.add_json_file("zh_TW", "langs/zh_TW.json").unwrap();
+    builder.add_alias("zh_HK", "zh_TW");
+
+    let ctx = builder.build().unwrap();
+
+    let bytes = ctx.to_binary();
+
+    let loaded = JSONGetText::<String>::from_binary(&bytes).unwrap();
+
+    assert_eq!(ctx.get_aliases(), loaded.get_aliases());
+    assert_eq!(ctx.get_source("zh_TW"), loaded.get_source("zh_TW"));
+    assert_eq!(Some("A greeting"), loaded.get_description("hello"));
+    assert_eq!("哈囉，世界！", loaded.get_text_with_key("zh_HK", "hello").unwrap());
+    assert_eq!(
+        r#"{"one":"{count} apple","other":"{count} apples"}"#,
+        loaded.get_text("apples").unwrap().to_json_string()
+    );
+    assert_eq!(bytes, loaded.to_binary());
+}
+
+#[test]
+fn binary_file() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let path = std::env::temp_dir().join(format!("json-gettext-binary-{}.bin", std::process::id()));
+
+    std::fs::write(&path, ctx.to_binary()).unwrap();
+
+    let catalog: JSONGetTextBinary = JSONGetText::from_binary_file(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!("哈囉，世界！", catalog.get().get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!(ctx.to_binary(), catalog.as_bytes());
+
+    let ctx = catalog.into_owned();
+
+    assert_eq!("Rust!", ctx.get_text("rust").unwrap());
+
+    assert!(matches!(
+        JSONGetTextBinary::<String>::from_bytes(vec![0; 8]),
+        Err(JSONGetTextBuildError::FormatError(JSONGetTextFormatError::InvalidFormat(_)))
+    ));
+}
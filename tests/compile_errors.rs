@@ -0,0 +1,8 @@
+#![cfg(feature = "macros")]
+
+#[test]
+fn compile_errors() {
+    let t = trybuild::TestCases::new();
+
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate json_gettext;
+
+#[test]
+fn static_handle() {
+    let mut ctx = static_json_gettext_build_handle!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!(cfg!(any(not(debug_assertions), feature = "embed")), ctx.is_embedded());
+    assert!(!ctx.reload_if_needed().unwrap());
+    assert!(!ctx.is_modified().unwrap());
+
+    assert_eq!("Hello, world!", get_text!(ctx, "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+}
+
+#[cfg(all(debug_assertions, not(feature = "embed")))]
+#[test]
+fn reload_modified_files() {
+    use std::{fs, thread, time::Duration};
+
+    use json_gettext::JSONGetTextHandle;
+
+    let dir = std::env::temp_dir().join(format!("json-gettext-handle-{}", std::process::id()));
+
+    fs::create_dir_all(&dir).unwrap();
+
+    let en = dir.join("en.json");
+    let zh = dir.join("zh.json");
+
+    fs::write(&en, r#"{"hello": "Hello!"}"#).unwrap();
+    fs::write(&zh, r#"{"hello": "哈囉！"}"#).unwrap();
+
+    let mut ctx =
+        JSONGetTextHandle::from_files("en", vec![("en", en.clone()), ("zh", zh.clone())]).unwrap();
+
+    assert!(!ctx.reload_if_needed().unwrap());
+
+    // make sure the modification time changes
+    thread::sleep(Duration::from_millis(1100));
+
+    fs::write(&zh, r#"{"hello": "你好！"}"#).unwrap();
+
+    assert!(ctx.is_modified().unwrap());
+    assert!(ctx.reload_if_needed().unwrap());
+    assert!(!ctx.is_modified().unwrap());
+    assert_eq!("你好！", get_text!(ctx, "zh", "hello").unwrap());
+
+    // a broken file keeps the previous context
+    thread::sleep(Duration::from_millis(1100));
+
+    fs::write(&zh, r#"{"hello": "#).unwrap();
+
+    assert!(ctx.reload_if_needed().is_err());
+    assert_eq!("你好！", get_text!(ctx, "zh", "hello").unwrap());
+
+    // the broken file is not read again until it is modified
+    assert!(!ctx.reload_if_needed().unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,98 @@
+use json_gettext::{escape_html, format_html, is_markdown_text_id, HTMLText, JSONGetText};
+
+#[test]
+fn escape_arguments_only() {
+    assert_eq!(
+        "Tom &amp; &quot;Jerry&quot; &#x27;&lt;/&gt;&#x27;",
+        escape_html("Tom & \"Jerry\" '</>'")
+    );
+    assert_eq!("plain", escape_html("plain"));
+
+    assert_eq!(
+        "<b>&lt;script&gt;</b> &amp; {missing}",
+        format_html("<b>{name}</b> &amp; {missing}", &[("name", &"<script>")])
+    );
+
+    assert_eq!("&lt;b&gt;", HTMLText::from_untrusted("<b>"));
+    assert_eq!("<b>", HTMLText::from_trusted("<b>").into_string());
+
+    assert!(is_markdown_text_id("intro.md"));
+    assert!(is_markdown_text_id("intro_md"));
+    assert!(!is_markdown_text_id("intro"));
+}
+
+#[test]
+fn render_html() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello, <b>{name}</b>!", "intro.md": "Hi **{name}**", "count": 1}"#,
+        )
+        .unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "哈囉，<b>{name}</b>！"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(
+        "Hello, <b>&lt;i&gt;Len&lt;/i&gt;</b>!",
+        ctx.render_html("hello", &[("name", &"<i>Len</i>")]).unwrap()
+    );
+    assert_eq!(
+        "哈囉，<b>Len &amp; Magic</b>！",
+        ctx.render_html_with_key("zh_TW", "hello", &[("name", &"Len & Magic")]).unwrap()
+    );
+    assert!(ctx.render_html("count", &[]).is_none());
+    assert!(ctx.render_html("missing", &[]).is_none());
+
+    #[cfg(feature = "markdown")]
+    assert_eq!(
+        "<p>Hi <strong>*&lt;Len&gt;*</strong></p>\n",
+        ctx.render_html("intro.md", &[("name", &"*<Len>*")]).unwrap()
+    );
+
+    #[cfg(not(feature = "markdown"))]
+    assert_eq!("Hi **&lt;Len&gt;**", ctx.render_html("intro.md", &[("name", &"<Len>")]).unwrap());
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown_arguments() {
+    use json_gettext::format_markdown;
+
+    assert_eq!(
+        "<p>Run <code>rm -rf *.&lt;tmp&gt;</code> as <em>a_b</em></p>\n",
+        format_markdown("Run `{cmd}` as *{user}*", &[("cmd", &"rm -rf *.<tmp>"), ("user", &"a_b")])
+    );
+    assert_eq!(
+        "<p><a href=\"/users/&quot;x&quot;\">jsongettextarg0jsongettextarg</a></p>\n",
+        format_markdown("[{name}](/users/{id})", &[
+            ("name", &"jsongettextarg0jsongettextarg"),
+            ("id", &"\"x\"")
+        ])
+    );
+    assert_eq!(
+        "<pre><code>{x} jsongettextarg1</code></pre>\n",
+        format_markdown("    {{x}} jsongettextarg{n}", &[("n", &1)])
+    );
+}
+
+#[cfg(feature = "rocket")]
+#[rocket::get("/")]
+fn index() -> HTMLText {
+    format_html("<p>{name}</p>", &[("name", &"<Len>")])
+}
+
+#[cfg(feature = "rocket")]
+#[test]
+fn responder() {
+    use rocket::{http::ContentType, local::blocking::Client};
+
+    let client = Client::untracked(rocket::build().mount("/", rocket::routes![index])).unwrap();
+
+    let response = client.get("/").dispatch();
+
+    assert_eq!(Some(ContentType::HTML), response.content_type());
+    assert_eq!("<p>&lt;Len&gt;</p>", response.into_string().unwrap());
+}
@@ -0,0 +1,61 @@
+use json_gettext::parse_i18next;
+
+#[test]
+fn nested_keys() {
+    let map = parse_i18next(r#"{"nav": {"home": "Home", "about": {"title": "About"}}}"#).unwrap();
+
+    assert_eq!("Home", map.get("nav.home").unwrap());
+    assert_eq!("About", map.get("nav.about.title").unwrap());
+}
+
+#[test]
+fn plurals() {
+    let map = parse_i18next(
+        r#"{
+            "item_one": "{{count}} item",
+            "item_other": "{{count}} items",
+            "apple": "an apple",
+            "apple_plural": "{{count}} apples",
+            "friend_male": "A boyfriend",
+            "sort_other": "Other sorting",
+            "none_zero": "No items",
+            "none_other": "{{count}} items"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        r#"{"one":"{{count}} item","other":"{{count}} items"}"#,
+        map.get("item").unwrap().to_json_string()
+    );
+    assert_eq!(
+        r#"{"one":"an apple","other":"{{count}} apples"}"#,
+        map.get("apple").unwrap().to_json_string()
+    );
+    assert_eq!("A boyfriend", map.get("friend_male").unwrap());
+    assert_eq!("Other sorting", map.get("sort_other").unwrap());
+    assert!(!map.contains_key("sort"));
+    assert_eq!(
+        r#"{"other":"{{count}} items","zero":"No items"}"#,
+        map.get("none").unwrap().to_json_string()
+    );
+}
+
+#[test]
+fn nesting() {
+    let map = parse_i18next(
+        r#"{
+            "app": "Rust",
+            "title": "$t(app) is great",
+            "subtitle": "$t(title)!",
+            "count": "$t(item, {\"count\": 2})",
+            "missing": "$t(nothing)"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!("Rust is great", map.get("title").unwrap());
+    assert_eq!("Rust is great!", map.get("subtitle").unwrap());
+    assert_eq!("$t(item, {\"count\": 2})", map.get("count").unwrap());
+    assert_eq!("$t(nothing)", map.get("missing").unwrap());
+}
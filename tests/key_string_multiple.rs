@@ -1,11 +1,9 @@
-#![cfg(not(feature = "langid"))]
-
 #[macro_use]
 extern crate json_gettext;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
 
-use json_gettext::JSONGetTextValue;
+use json_gettext::{JSONGetText, JSONGetTextValue};
 
 #[test]
 fn multiple_get() {
@@ -31,3 +29,142 @@ fn multiple_get() {
     assert_eq!(&"哈囉，世界！", map_zh.get("hello").unwrap());
     assert_eq!(&"Rust!", map_zh.get("rust").unwrap());
 }
+
+#[test]
+fn fallback_map() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let map_zh = ctx.get("zh_TW");
+
+    assert_eq!(2, map_zh.len());
+    assert_eq!(&"Rust!", map_zh.get("rust").unwrap());
+    assert!(map_zh.is_translated("hello"));
+    assert!(!map_zh.is_translated("rust"));
+    assert_eq!(1, map_zh.translated().count());
+
+    let map_de = ctx.get("de");
+
+    assert_eq!(2, map_de.iter().count());
+    assert_eq!(0, map_de.translated().count());
+    assert_eq!(&"Hello, world!", map_de.get("hello").unwrap());
+}
+
+#[test]
+fn normalized_keys_and_aliases() {
+    let mut builder = JSONGetText::build("en-us");
+
+    builder.add_json_file("EN_us", "langs/en_US.json").unwrap();
+    builder.add_json_file("zh-hant-tw", "langs/zh_TW.json").unwrap();
+    builder.add_alias("zh-TW", "zh_Hant_TW").add_alias("iw", "he");
+
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort();
+
+    assert_eq!(vec!["en_US", "zh_Hant_TW"], keys);
+    assert_eq!("en_US", ctx.get_default_key());
+    assert!(ctx.contains_key("en-US"));
+    assert_eq!("哈囉，世界！", get_text!(ctx, "ZH-HANT-TW", "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh-tw", "hello").unwrap());
+    assert_eq!("Hello, world!", get_text!(ctx, "iw", "hello").unwrap());
+    assert_eq!(Some(&String::from("zh_Hant_TW")), ctx.get_aliases().get("zh_TW"));
+}
+
+#[test]
+fn lookup_provenance() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json_file("en_US", "langs/en_US.json").unwrap();
+    builder.add_json_file("zh_TW", "langs/zh_TW.json").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let lookup = ctx.lookup("zh-tw", "hello").unwrap();
+
+    assert_eq!("哈囉，世界！", lookup.value);
+    assert_eq!("zh_TW", lookup.requested_key);
+    assert_eq!("zh_TW", lookup.resolved_key);
+    assert!(!lookup.is_fallback());
+    assert_eq!(Some(Path::new("langs/zh_TW.json")), lookup.source);
+
+    let lookup = ctx.lookup("zh_TW", "rust").unwrap();
+
+    assert_eq!("Rust!", lookup.value);
+    assert!(lookup.copied_from_default);
+    assert!(!lookup.is_key_fallback());
+    assert_eq!(Some(Path::new("langs/en_US.json")), lookup.source);
+
+    let lookup = ctx.lookup("de", "hello").unwrap();
+
+    assert_eq!("Hello, world!", lookup.value);
+    assert_eq!("de", lookup.requested_key);
+    assert_eq!("en_US", lookup.resolved_key);
+    assert!(lookup.is_key_fallback());
+
+    assert!(ctx.lookup("zh_TW", "missing").is_none());
+
+    // keys are borrowed like `get` does
+    let key = ctx.get_keys().into_iter().find(|key| key.as_str() == "zh_TW").unwrap();
+
+    assert_eq!("哈囉，世界！", ctx.lookup(key, "hello").unwrap().value);
+
+    let map = ctx.lookup_multiple("zh_TW", &["hello", "rust"]).unwrap();
+
+    assert!(!map["hello"].copied_from_default);
+    assert!(map["rust"].copied_from_default);
+
+    let map = ctx.lookup_filtered("zh_TW", &regex::Regex::new("^h").unwrap()).unwrap();
+
+    assert_eq!(1, map.len());
+    assert_eq!("哈囉，世界！", map["hello"].value);
+}
+
+#[cfg(not(feature = "macros"))]
+#[test]
+fn static_build_errors() {
+    use json_gettext::JSONGetTextBuildError;
+
+    let result = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "tests/data/broken.json",
+    );
+
+    assert!(matches!(result, Err(JSONGetTextBuildError::SerdeJSONError(_))));
+
+    let result = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "en_US" => "langs/zh_TW.json",
+    );
+
+    assert!(matches!(result, Err(JSONGetTextBuildError::DuplicatedKey(_))));
+}
+
+#[test]
+fn shared_owned_json() {
+    let mut builder = JSONGetText::build("en_US");
+
+    let json = String::from(
+        r##"{"hello": "Hello!", "days": ["Sun", "Mon"], "apples": {"one": "# apple"}}"##,
+    );
+
+    builder.add_json_owned("en_US", &json).unwrap();
+    builder.add_json_file("zh_TW", "langs/zh_TW.json").unwrap();
+
+    drop(json);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", get_text!(ctx, "hello").unwrap());
+    assert_eq!("Mon", get_text!(ctx, "days").unwrap().get(1).unwrap());
+    assert_eq!("# apple", get_text!(ctx, "zh_TW", "apples").unwrap().get("one").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+}
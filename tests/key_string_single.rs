@@ -1,5 +1,3 @@
-#![cfg(not(feature = "langid"))]
-
 #[macro_use]
 extern crate json_gettext;
 
@@ -0,0 +1,112 @@
+#![cfg(feature = "langid")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{
+    unic_langid::{
+        subtags::{Language, Region},
+        LanguageIdentifier,
+    },
+    JSONGetText,
+};
+
+#[test]
+fn string_and_langid_keys() {
+    let ctx_string = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let ctx_language = static_json_gettext_build!(
+        key!(language: "en");
+        key!(language: "en") => "langs/en_US.json",
+        key!(language: "zh") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let ctx_region = static_json_gettext_build!(
+        key!(region: "US");
+        key!(region: "US") => "langs/en_US.json",
+        key!(region: "TW") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let ctx_pair = static_json_gettext_build!(
+        key!(language_region_pair: "en_US");
+        key!(language_region_pair: "en_US") => "langs/en_US.json",
+        key!(language_region_pair: "zh_TW") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let ctx_langid = static_json_gettext_build!(
+        key!(langid: "en-US");
+        key!(langid: "en-US") => "langs/en_US.json",
+        key!(langid: "zh-Hant-TW") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!("哈囉，世界！", get_text!(ctx_string, "zh_TW", "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx_language, key!(language: "zh"), "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx_region, key!(region: "TW"), "hello").unwrap());
+    assert_eq!(
+        "哈囉，世界！",
+        get_text!(ctx_pair, key!(language_region_pair: "zh_TW"), "hello").unwrap()
+    );
+    assert_eq!("哈囉，世界！", get_text!(ctx_langid, key!(langid: "zh-Hant-TW"), "hello").unwrap());
+
+    assert_eq!("Hello, world!", get_text!(ctx_langid, key!(langid: "zh-TW"), "hello").unwrap());
+    assert_eq!("Rust!", get_text!(ctx_pair, key!(language_region_pair: "zh_TW"), "rust").unwrap());
+}
+
+#[test]
+fn binary_keys() {
+    let mut builder = JSONGetText::<(Language, Option<Region>)>::build(key!(
+        language_region_pair: "en_US"
+    ));
+
+    builder.add_json_file(key!(language_region_pair: "en_US"), "langs/en_US.json").unwrap();
+    builder.add_json_file(key!(language_region_pair: "zh"), "langs/zh_TW.json").unwrap();
+
+    let bytes = builder.build().unwrap().to_binary();
+
+    let ctx = JSONGetText::<(Language, Option<Region>)>::from_binary(&bytes).unwrap();
+
+    assert_eq!(&key!(language_region_pair: "en_US"), ctx.get_default_key());
+    assert_eq!("哈囉，世界！", get_text!(ctx, key!(language_region_pair: "zh"), "hello").unwrap());
+
+    let ctx = JSONGetText::<LanguageIdentifier>::from_binary(&bytes).unwrap();
+
+    assert_eq!(&key!(langid: "en-US"), ctx.get_default_key());
+    assert_eq!("哈囉，世界！", get_text!(ctx, key!(langid: "zh"), "hello").unwrap());
+}
+
+#[test]
+fn langid_fallback() {
+    let ctx = static_json_gettext_build!(
+        key!(langid: "en");
+        key!(langid: "en") => "langs/en_US.json",
+        key!(langid: "zh-Hant") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!("哈囉，世界！", get_text!(ctx, key!(langid: "zh-Hant-HK"), "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, key!(langid: "zh-Hant-TW-pinyin"), "hello").unwrap());
+    assert_eq!("Hello, world!", get_text!(ctx, key!(langid: "zh-Hans-CN"), "hello").unwrap());
+    assert_eq!("Hello, world!", get_text!(ctx, key!(langid: "zh"), "hello").unwrap());
+    assert!(!ctx.contains_key(key!(langid: "zh-Hant-HK")));
+
+    let ctx = static_json_gettext_build!(
+        key!(language_region_pair: "en");
+        key!(language_region_pair: "en") => "langs/en_US.json",
+        key!(language_region_pair: "zh") => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!(
+        "哈囉，世界！",
+        get_text!(ctx, key!(language_region_pair: "zh_TW"), "hello").unwrap()
+    );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "macros")]
+
+#[macro_use]
+extern crate json_gettext;
+
+json_gettext::catalog!("langs/en_US.json");
+
+pub mod nested {
+    json_gettext::catalog!(Messages, "langs/en_US.json");
+}
+
+#[test]
+fn text_id() {
+    assert_eq!("hello", TextId::Hello.as_str());
+    assert_eq!("rust", TextId::Rust.as_ref());
+    assert_eq!("rust", TextId::Rust.to_string());
+    assert_eq!([TextId::Hello, TextId::Rust], TextId::ALL);
+
+    assert_eq!("hello", nested::Messages::Hello.as_str());
+}
+
+#[test]
+fn get_text_by_text_id() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!("Hello, world!", get_text!(ctx, TextId::Hello).unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", TextId::Hello).unwrap());
+    assert_eq!("Rust!", get_text!(ctx, "zh_TW", TextId::Rust).unwrap());
+}
+
+#[test]
+fn strict() {
+    let ctx = static_json_gettext_build!(
+        #![strict]
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "en_GB" => "langs/en_US.json",
+    )
+    .unwrap();
+
+    assert!(ctx.get("en_GB").is_translated("rust"));
+}
+
+#[test]
+fn normalized_keys() {
+    let ctx = static_json_gettext_build!(
+        #![strict]
+        "en-US";
+        "en_US" => "langs/en_US.json",
+        "en-gb" => "langs/en_US.json",
+    )
+    .unwrap();
+
+    assert_eq!("en_US", ctx.get_default_key());
+    assert_eq!("Hello, world!", get_text!(ctx, "en_GB", "hello").unwrap());
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "macros")]
+
+#[macro_use]
+extern crate json_gettext;
+
+json_gettext::messages!(msgs, "tests/data/messages/en_US.json");
+
+#[test]
+fn message_functions() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "tests/data/messages/en_US.json",
+        "zh_TW" => "tests/data/messages/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!("Welcome, Len!", msgs::welcome(&ctx, "en_US", "Len"));
+    assert_eq!("歡迎，Len！", msgs::welcome(&ctx, "zh_TW", "Len"));
+    assert_eq!("Goodbye!", msgs::goodbye(&ctx, "zh_TW"));
+    assert_eq!("Len has 3 items in the cart. {total}", msgs::cart_summary(&ctx, "en_US", "Len", 3));
+}
+
+#[test]
+fn format_text() {
+    assert_eq!(
+        "1 + 2 = {sum}",
+        json_gettext::format_text("{a} + {b} = {sum}", &[("a", &1), ("b", &2)])
+    );
+}
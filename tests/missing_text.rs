@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate json_gettext;
+
+use std::sync::Arc;
+
+use json_gettext::{MissingTextReport, PlaceholderMissingTextHandler};
+
+#[test]
+fn report() {
+    let mut ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let report = Arc::new(MissingTextReport::new());
+
+    ctx.set_missing_text_handler(report.clone());
+
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+    assert!(report.records().is_empty());
+
+    let line = line!() + 1;
+    assert_eq!("Rust!", get_text!(ctx, "zh_TW", "rust").unwrap());
+
+    for _ in 0..2 {
+        assert!(get_text!(ctx, "zh_TW", "missing").is_none());
+    }
+
+    let records = report.records();
+
+    assert_eq!(2, records.len());
+    assert_eq!("zh_TW", records[0].key);
+    assert_eq!("missing", records[0].text);
+    assert_eq!("not_found", records[0].kind);
+    assert_eq!(2, records[0].count);
+    assert_eq!("rust", records[1].text);
+    assert_eq!("untranslated", records[1].kind);
+    assert_eq!(file!(), records[1].file);
+    assert_eq!(line, records[1].line);
+
+    assert!(report.to_json().starts_with(r#"[{"column":"#));
+
+    report.clear();
+
+    assert!(report.records().is_empty());
+}
+
+#[test]
+fn placeholder() {
+    let mut ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    ctx.set_missing_text_handler(PlaceholderMissingTextHandler::default());
+
+    assert_eq!("[[missing:hello2]]", get_text!(ctx, "zh_TW", "hello2").unwrap());
+    assert_eq!("Rust!", get_text!(ctx, "zh_TW", "rust").unwrap());
+
+    let map = get_text!(ctx, "zh_TW", "hello", "hello2").unwrap();
+
+    assert_eq!("[[missing:hello2]]", map["hello2"]);
+
+    ctx.set_missing_text_handler(
+        PlaceholderMissingTextHandler::new("[{key}:{text}]").with_untranslated(true),
+    );
+
+    assert_eq!("[zh_TW:rust]", get_text!(ctx, "zh_TW", "rust").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+
+    ctx.remove_missing_text_handler();
+
+    assert!(get_text!(ctx, "zh_TW", "hello2").is_none());
+}
@@ -0,0 +1,61 @@
+use json_gettext::{pseudo_localize, JSONGetText, JSONGetTextBuildError, PseudoLocaleOptions};
+
+#[test]
+fn pseudo_localize_texts() {
+    let options = PseudoLocaleOptions::accented();
+
+    assert_eq!("[Ḥḗŀŀǿ~~]", pseudo_localize("Hello", &options));
+    assert_eq!(
+        "[Ḥī {name}, %1$s &amp; <a href=\"x\">ŀīƞķ</a> {count, plural, one {# item}}~~~~]",
+        pseudo_localize(
+            "Hi {name}, %1$s &amp; <a href=\"x\">link</a> {count, plural, one {# item}}",
+            &options
+        )
+    );
+    assert_eq!(
+        "{a} 100% ş",
+        pseudo_localize("{a} 100% s", &PseudoLocaleOptions {
+            expansion: 0,
+            brackets: false,
+            ..options
+        })
+    );
+
+    assert_eq!(
+        "\u{202E}Hello, \u{202C}{name}\u{202E}!\u{202C}",
+        pseudo_localize("Hello, {name}!", &PseudoLocaleOptions::mirrored())
+    );
+}
+
+#[test]
+fn pseudo_locales() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r##"{"hello": "Hello, {name}!", "apples": {"one": "# apple", "other": "# apples"}}"##,
+        )
+        .unwrap();
+    builder.add_pseudo_locale("en_XA", &PseudoLocaleOptions::accented()).unwrap();
+    builder.add_pseudo_locale("ar_XB", &PseudoLocaleOptions::mirrored()).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("[Ḥḗŀŀǿ, {name}!~~~]", ctx.get_text_with_key("en_XA", "hello").unwrap());
+    assert_eq!(
+        "\u{202E}Hello, \u{202C}{name}\u{202E}!\u{202C}",
+        ctx.get_text_with_key("ar_XB", "hello").unwrap()
+    );
+
+    let apples = ctx.get_text_with_key("en_XA", "apples").unwrap();
+
+    assert_eq!(r##"{"one":"[# ȧƥƥŀḗ~~~]","other":"[# ȧƥƥŀḗş~~~]"}"##, apples.to_json_string());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    assert!(matches!(
+        builder.add_pseudo_locale("en_XA", &PseudoLocaleOptions::accented()),
+        Err(JSONGetTextBuildError::DefaultKeyNotFound)
+    ));
+}
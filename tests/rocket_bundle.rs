@@ -0,0 +1,61 @@
+#![cfg(feature = "rocket")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::JSONGetTextManager;
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+
+fn client() -> Client {
+    let rocket = rocket::build()
+        .attach(static_json_gettext_build_for_rocket!(
+            "en_US";
+            "en_US" => "langs/en_US.json",
+            "zh_TW" => "langs/zh_TW.json",
+        ))
+        .mount("/i18n", JSONGetTextManager::<String>::bundle_routes());
+
+    Client::untracked(rocket).unwrap()
+}
+
+#[test]
+fn bundles() {
+    let client = client();
+
+    let response = client.get("/i18n/zh-tw").dispatch();
+
+    assert_eq!(Status::Ok, response.status());
+    assert_eq!(Some(ContentType::JSON), response.content_type());
+    assert_eq!(Some("no-cache"), response.headers().get_one("Cache-Control"));
+    assert_eq!(Some("Accept-Language"), response.headers().get_one("Vary"));
+
+    let etag = response.headers().get_one("ETag").unwrap().to_string();
+
+    assert!(etag.starts_with("\"0-"));
+    assert_eq!(r#"{"hello":"哈囉，世界！","rust":"Rust!"}"#, response.into_string().unwrap());
+
+    let response =
+        client.get("/i18n/zh_TW").header(Header::new("If-None-Match", etag.clone())).dispatch();
+
+    assert_eq!(Status::NotModified, response.status());
+    assert_eq!(Some(etag.as_str()), response.headers().get_one("ETag"));
+    assert!(response.into_string().is_none());
+
+    let response = client
+        .get("/i18n/zh_TW?filter=%5Eh")
+        .header(Header::new("If-None-Match", etag.clone()))
+        .dispatch();
+
+    assert_eq!(Status::Ok, response.status());
+    assert_ne!(Some(etag.as_str()), response.headers().get_one("ETag"));
+    assert_eq!(r#"{"hello":"哈囉，世界！"}"#, response.into_string().unwrap());
+
+    assert_eq!(Status::BadRequest, client.get("/i18n/zh_TW?filter=(").dispatch().status());
+    assert_eq!(
+        Status::BadRequest,
+        client.get("/i18n/zh_TW?filter=%5Cw%7B1000%7D%7B1000%7D").dispatch().status()
+    );
+}
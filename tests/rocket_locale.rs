@@ -0,0 +1,97 @@
+#![cfg(feature = "rocket")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{Locale, LocaleConfig, LocaleSource};
+use rocket::{
+    http::{Cookie, CookieJar, Header},
+    local::blocking::Client,
+    State,
+};
+
+#[rocket::get("/<_lang>/hello")]
+fn hello(_lang: &str, locale: Locale) -> String {
+    format!("{} {:?}", locale.as_str(), locale.get_source())
+}
+
+#[rocket::get("/switch?<lang>")]
+fn switch(lang: &str, config: &State<LocaleConfig>, cookies: &CookieJar<'_>) {
+    config.set_cookie(cookies, &lang.to_string());
+}
+
+fn build_client(config: LocaleConfig) -> Client {
+    let rocket = rocket::build()
+        .attach(static_json_gettext_build_for_rocket!(
+            "en_US";
+            "en_US" => "langs/en_US.json",
+            "zh_TW" => "langs/zh_TW.json",
+        ))
+        .manage(config)
+        .mount("/", rocket::routes![hello, switch]);
+
+    Client::tracked(rocket).unwrap()
+}
+
+#[test]
+fn locale_sources() {
+    let client = build_client(LocaleConfig::default());
+
+    assert_eq!("en_US None", client.get("/x/hello").dispatch().into_string().unwrap());
+    assert_eq!(
+        "zh_TW Some(Query(\"lang\"))",
+        client.get("/x/hello?lang=zh-tw").dispatch().into_string().unwrap()
+    );
+    assert_eq!(
+        "zh_TW Some(AcceptLanguage)",
+        client
+            .get("/x/hello?lang=fr")
+            .header(Header::new("Accept-Language", "fr, en-US;q=0.5, zh-TW;q=0.8, *;q=0.1"))
+            .dispatch()
+            .into_string()
+            .unwrap()
+    );
+    assert_eq!(
+        "zh_TW Some(Cookie)",
+        client
+            .get("/x/hello")
+            .cookie(Cookie::new("lang", "zh_TW"))
+            .header(Header::new("Accept-Language", "en-US"))
+            .dispatch()
+            .into_string()
+            .unwrap()
+    );
+
+    let client = build_client(LocaleConfig::new(vec![
+        LocaleSource::PathSegment(0),
+        LocaleSource::AcceptLanguage,
+    ]));
+
+    assert_eq!(
+        "zh_TW Some(PathSegment(0))",
+        client.get("/zh_TW/hello?lang=en_US").dispatch().into_string().unwrap()
+    );
+    assert_eq!(
+        "en_US None",
+        client
+            .get("/fr/hello")
+            .header(Header::new("Accept-Language", "zh-TW;q=0"))
+            .dispatch()
+            .into_string()
+            .unwrap()
+    );
+}
+
+#[test]
+fn locale_cookie() {
+    let client = build_client(LocaleConfig::default().with_cookie_name("locale"));
+
+    let response = client.get("/switch?lang=zh_TW").dispatch();
+
+    let cookie = response.cookies().get("locale").unwrap();
+
+    assert_eq!("zh_TW", cookie.value());
+    assert_eq!(Some("/"), cookie.path());
+
+    assert_eq!("zh_TW Some(Cookie)", client.get("/x/hello").dispatch().into_string().unwrap());
+}
@@ -0,0 +1,121 @@
+#![cfg(any(feature = "tera", feature = "handlebars"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{
+    rocket_dyn_templates::{context, Template},
+    JSONGetTextManager, Locale,
+};
+use rocket::local::blocking::Client;
+
+#[cfg(feature = "tera")]
+#[rocket::get("/tera?<count>")]
+fn hello_tera(locale: Locale, count: u32) -> Template {
+    Template::render("hello_tera", context! { lang: locale, name: "<Len>", count })
+}
+
+#[cfg(feature = "handlebars")]
+#[rocket::get("/hbs?<count>")]
+fn hello_hbs(locale: Locale, count: u32) -> Template {
+    Template::render("hello_hbs", context! { lang: locale, name: "<Len>", count })
+}
+
+#[cfg(feature = "tera")]
+#[rocket::get("/tera/missing")]
+fn missing_tera() -> Template {
+    Template::render("missing_tera", context! {})
+}
+
+fn client() -> Client {
+    let figment = rocket::Config::figment().merge(("template_dir", "tests/data/templates"));
+
+    let rocket = rocket::custom(figment)
+        .attach(static_json_gettext_build_for_rocket!(
+            "en_US";
+            "en_US" => "tests/data/templates/en_US.json",
+            "zh_TW" => "tests/data/templates/zh_TW.json",
+            "ru" => "tests/data/templates/ru.json",
+        ))
+        .attach(JSONGetTextManager::<String>::templates_fairing());
+
+    #[cfg(feature = "tera")]
+    let rocket = rocket.mount("/", rocket::routes![hello_tera, missing_tera]);
+
+    #[cfg(feature = "handlebars")]
+    let rocket = rocket.mount("/", rocket::routes![hello_hbs]);
+
+    Client::untracked(rocket).unwrap()
+}
+
+#[cfg(feature = "tera")]
+#[test]
+fn tera_functions() {
+    let client = client();
+
+    assert_eq!(
+        "Hello, &lt;Len&gt;! 1 apple",
+        client.get("/tera?count=1").dispatch().into_string().unwrap().trim()
+    );
+    assert_eq!(
+        "Hello, &lt;Len&gt;! 3 apples",
+        client.get("/tera?count=3").dispatch().into_string().unwrap().trim()
+    );
+    assert_eq!(
+        "哈囉，&lt;Len&gt;！ 1 個蘋果",
+        client.get("/tera?count=1&lang=zh-TW").dispatch().into_string().unwrap().trim()
+    );
+    assert_eq!(
+        "Hello, &lt;Len&gt;! 0 apples",
+        client.get("/tera?count=0").dispatch().into_string().unwrap().trim()
+    );
+}
+
+#[cfg(feature = "tera")]
+#[test]
+fn cldr_plural_rules() {
+    let client = client();
+
+    for (count, apples) in [(1, "1 яблоко"), (3, "3 яблока"), (5, "5 яблок"), (21, "21 яблоко")]
+    {
+        assert_eq!(
+            format!("Привет, &lt;Len&gt;! {}", apples),
+            client
+                .get(format!("/tera?count={}&lang=ru", count))
+                .dispatch()
+                .into_string()
+                .unwrap()
+                .trim()
+        );
+    }
+}
+
+#[cfg(feature = "handlebars")]
+#[test]
+fn handlebars_helpers() {
+    let client = client();
+
+    assert_eq!(
+        "Hello, &lt;Len&gt;! 1 apple",
+        client.get("/hbs?count=1").dispatch().into_string().unwrap().trim()
+    );
+    assert_eq!(
+        "哈囉，&lt;Len&gt;！ 2 個蘋果",
+        client.get("/hbs?count=2&lang=zh_TW").dispatch().into_string().unwrap().trim()
+    );
+    assert_eq!(
+        "Привет, &lt;Len&gt;! 22 яблока",
+        client.get("/hbs?count=22&lang=ru").dispatch().into_string().unwrap().trim()
+    );
+}
+
+#[cfg(feature = "tera")]
+#[test]
+fn missing_template_text() {
+    let client = client();
+
+    assert_eq!(
+        rocket::http::Status::InternalServerError,
+        client.get("/tera/missing").dispatch().status()
+    );
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate json_gettext;
+
+fn main() {
+    // the keys are checked before the files are read, and `Cargo.toml` exists in the directory where this file is compiled
+    let _ = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "Cargo.toml",
+        "en-us" => "Cargo.toml",
+    );
+}
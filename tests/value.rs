@@ -1,4 +1,10 @@
-use json_gettext::JSONGetTextValue;
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashMap},
+    thread,
+};
+
+use json_gettext::{JSONEscapeOptions, JSONGetTextValue, JSONTree};
 
 #[test]
 fn no_double_quotes() {
@@ -23,3 +29,219 @@ fn escape_double_quotes() {
     );
     assert_eq!("\"Test \\\"abc\\\"\"", JSONGetTextValue::from_str("Test \"abc\"").to_json_string());
 }
+
+#[test]
+fn escape_rfc_8259() {
+    assert_eq!(
+        "\"e\u{301} it's\\u0001\\n\\t\\\\\"",
+        JSONGetTextValue::from_str("e\u{301} it's\u{1}\n\t\\").to_json_string()
+    );
+
+    let value = JSONGetTextValue::from_str("</script> & <b>");
+
+    assert_eq!("\"</script> & <b>\"", value.to_json_string());
+    assert_eq!(
+        "\"\\u003c\\/script\\u003e \\u0026 \\u003cb\\u003e\"",
+        value.to_json_string_with_options(&JSONEscapeOptions::html())
+    );
+
+    let value = JSONGetTextValue::parse_json(r#"{"a":["</b>"]}"#).unwrap();
+
+    let mut bytes = Vec::new();
+
+    value
+        .write_json(&mut bytes, &JSONEscapeOptions {
+            escape_slash: true,
+            ..JSONEscapeOptions::default()
+        })
+        .unwrap();
+
+    assert_eq!(r#"{"a":["<\/b>"]}"#, String::from_utf8(bytes).unwrap());
+
+    let value = JSONGetTextValue::from_str("a\u{2028}b\u{2029}c\u{2027}");
+
+    assert_eq!("\"a\u{2028}b\u{2029}c\u{2027}\"", value.to_json_string());
+    assert_eq!(
+        "\"a\\u2028b\\u2029c\u{2027}\"",
+        value.to_json_string_with_options(&JSONEscapeOptions::html())
+    );
+    assert_eq!(
+        "\"a\\u2028b\\u2029c\u{2027}\"",
+        value.to_json_string_with_options(&JSONEscapeOptions {
+            escape_line_separators: true,
+            ..JSONEscapeOptions::default()
+        })
+    );
+}
+
+#[test]
+fn typed_accessors() {
+    let value = JSONGetTextValue::parse_json(
+        r#"{"days": ["Sun", "Mon"], "count": -2, "size": 3, "ratio": 0.5, "ok": true, "none": null}"#,
+    )
+    .unwrap();
+
+    assert_eq!(Some(-2), value["count"].as_i64());
+    assert_eq!(Some(3), value.get("size").unwrap().as_u64());
+    assert_eq!(Some(0.5), value.get("ratio").unwrap().as_f64());
+    assert_eq!(Some(true), value.get("ok").unwrap().as_bool());
+    assert!(value.get("none").unwrap().is_null());
+    assert!(value.get("missing").is_none());
+    assert!(value["missing"].is_null());
+    assert_eq!(2, value.as_object().unwrap().get("days").unwrap().as_array().unwrap().len());
+    assert_eq!("Mon", value.get("days").unwrap().get(1).unwrap().as_str().unwrap());
+    assert_eq!("Sun", value["days"][0]);
+    assert_eq!(6, value.iter_object().unwrap().len());
+
+    let days: Vec<String> = value.get("days").unwrap().deserialize_into().unwrap();
+
+    assert_eq!(vec!["Sun", "Mon"], days);
+
+    let value = JSONGetTextValue::from_str("Test");
+
+    assert_eq!("Test", value.deserialize_into::<String>().unwrap());
+    assert!(value.deserialize_into::<u64>().is_err());
+    assert!(value.as_i64().is_none());
+    assert!(!value.is_null());
+    assert!(value.get(0).is_none());
+}
+
+#[test]
+fn borrowed_tree() {
+    let json = r##"{"apples": {"one": "# apple", "other": "# apples\n"}, "days": ["Sun"]}"##;
+
+    let value: JSONGetTextValue = serde_json::from_str(json).unwrap();
+
+    assert!(matches!(value, JSONGetTextValue::Tree(JSONTree::Object(_))));
+
+    let apples = value.get("apples").unwrap();
+    let one = apples.get("one").unwrap();
+
+    assert_eq!("# apple", one);
+    assert!(json.as_bytes().as_ptr_range().contains(&one.as_str().unwrap().as_ptr()));
+    assert_eq!("# apples\n", apples.get("other").unwrap());
+    assert_eq!(
+        r##"{"apples":{"one":"# apple","other":"# apples\n"},"days":["Sun"]}"##,
+        value.to_json_string()
+    );
+
+    let days: Vec<String> = value.get("days").unwrap().deserialize_into().unwrap();
+
+    assert_eq!(vec!["Sun"], days);
+
+    assert!(value.as_object().is_none());
+    assert!(value["apples"].is_null());
+    assert_eq!(Some("# apple"), value.as_tree().unwrap()["apples"]["one"].as_str());
+    assert!(value.as_tree().unwrap()["apples"]["two"].is_null());
+    assert_eq!(
+        vec!["apples", "days"],
+        value.iter_object().unwrap().map(|(k, _)| k).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["Sun"],
+        value.get("days").unwrap().iter_array().unwrap().map(|v| v.to_string()).collect::<Vec<_>>()
+    );
+
+    let value: JSONGetTextValue = serde_json::from_str("null").unwrap();
+
+    assert_eq!("null", value.to_json_string());
+
+    let value: JSONGetTextValue = serde_json::from_str("\"Test\"").unwrap();
+
+    assert_eq!("Test", value.as_str().unwrap());
+}
+
+#[test]
+fn owned_and_comparable() {
+    let json = String::from(r#"{"one": "apple", "other": "apples"}"#);
+
+    let value: JSONGetTextValue = serde_json::from_str(&json).unwrap();
+    let text = String::from("Hello");
+
+    let owned = (JSONGetTextValue::from_str(&text).into_owned(), value.clone().into_owned());
+
+    drop(text);
+
+    let (hello, apples) = thread::spawn(move || owned).join().unwrap();
+
+    assert!(matches!(hello, JSONGetTextValue::Str(Cow::Owned(_))));
+    assert_eq!("Hello", hello);
+    assert_eq!(value, apples);
+
+    assert!(matches!(JSONGetTextValue::from_string("Hi"), JSONGetTextValue::Str(Cow::Owned(_))));
+    assert_eq!(
+        JSONGetTextValue::from_str("Hi"),
+        JSONGetTextValue::from_json_value(serde_json::Value::from("Hi"))
+    );
+    assert_eq!(
+        JSONGetTextValue::parse_json(r#"{"other": "apples", "one": "apple"}"#).unwrap(),
+        value
+    );
+    assert_ne!(JSONGetTextValue::from_u8(1), JSONGetTextValue::from_f64(1.0));
+
+    let mut counts = HashMap::new();
+
+    *counts.entry(value.clone_borrowed()).or_insert(0) += 1;
+    *counts.entry(JSONGetTextValue::parse_json(&json).unwrap()).or_insert(0) += 1;
+
+    assert_eq!(Some(&2), counts.get(&apples));
+
+    let sorted: Vec<String> = [
+        JSONGetTextValue::from_str("b"),
+        JSONGetTextValue::from_i32(-1),
+        JSONGetTextValue::null(),
+        JSONGetTextValue::from_str("a"),
+        JSONGetTextValue::from_bool(true),
+        JSONGetTextValue::from_f64(0.5),
+    ]
+    .into_iter()
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .map(|v| v.to_json_string())
+    .collect();
+
+    assert_eq!(vec!["null", "true", "-1", "0.5", "\"a\"", "\"b\""], sorted);
+}
+
+#[test]
+fn duplicated_object_keys() {
+    let json = r#"{"a": 1, "b": 2, "a": 3}"#;
+
+    let tree: JSONGetTextValue = serde_json::from_str(json).unwrap();
+    let value = JSONGetTextValue::from_json_value(serde_json::from_str(json).unwrap());
+
+    assert!(matches!(tree, JSONGetTextValue::Tree(_)));
+    assert!(matches!(value, JSONGetTextValue::JSONValue(_)));
+    assert_eq!(tree, value);
+    assert_eq!(r#"{"a":3,"b":2}"#, tree.to_json_string());
+    assert_eq!(3, tree.get("a").unwrap().as_i64().unwrap());
+
+    let mut counts = HashMap::new();
+
+    *counts.entry(tree).or_insert(0) += 1;
+    *counts.entry(value).or_insert(0) += 1;
+
+    assert_eq!(1, counts.len());
+}
+
+#[test]
+fn object_order() {
+    let unsorted = JSONGetTextValue::parse_json(r#"{"c": [1, 2], "b": 1, "a": 2}"#).unwrap();
+    let sorted = JSONGetTextValue::from_json_value(
+        serde_json::from_str(r#"{"a": 2, "b": 1, "c": [1, 2]}"#).unwrap(),
+    );
+
+    assert_eq!(unsorted, sorted);
+    assert!(unsorted < JSONGetTextValue::parse_json(r#"{"b": 2, "a": 2}"#).unwrap());
+    assert!(unsorted > JSONGetTextValue::parse_json(r#"{"b": 1, "a": 2}"#).unwrap());
+    assert!(
+        unsorted < JSONGetTextValue::parse_json(r#"{"c": [1, 2, 0], "b": 1, "a": 2}"#).unwrap()
+    );
+
+    let mut counts = HashMap::new();
+
+    *counts.entry(unsorted).or_insert(0) += 1;
+    *counts.entry(sorted).or_insert(0) += 1;
+
+    assert_eq!(1, counts.len());
+}